@@ -0,0 +1,138 @@
+//! Benchmarks parsing and both parts of every day, on the real input and on a large one.
+//!
+//! The large inputs are derived from the real ones, so they stay valid puzzle inputs:
+//! most days simply repeat their input, the others are built so every step stays solvable.
+
+use std::{fmt::Write, fs};
+
+use aoc::find_day;
+use aoc_common::Solution;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// How often the real input is repeated for the large variant.
+const SCALE: usize = 20;
+
+fn real_input(day: u8) -> String {
+    let day = find_day(day).expect("day is registered");
+    fs::read_to_string(day.input_path()).expect("real input is checked in")
+}
+
+fn bench_day<S: Solution>(c: &mut Criterion, inputs: &[(&str, String)]) {
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    for (name, input) in inputs {
+        group.bench_with_input(BenchmarkId::new("parse", name), input, |b, input| {
+            b.iter(|| S::parse(input).unwrap())
+        });
+        let parsed = S::parse(input).unwrap();
+        let config = S::Config::default();
+        group.bench_with_input(BenchmarkId::new("part1", name), &parsed, |b, parsed| {
+            b.iter(|| S::part1(parsed, &config).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("part2", name), &parsed, |b, parsed| {
+            b.iter(|| S::part2(parsed, &config).unwrap())
+        });
+    }
+    group.finish();
+}
+
+/// Repeats the non-empty lines of `input`, keeping the total a multiple of the original.
+fn repeat_lines(input: &str, times: usize) -> String {
+    let lines: Vec<_> = input.lines().filter(|line| !line.is_empty()).collect();
+    lines.repeat(times).join("\n")
+}
+
+/// Follows every move by moving the crates back, so the stock never runs dry.
+fn reversible_moves(input: &str, times: usize) -> String {
+    let (drawing, moves) = input.split_once("\n\n").expect("input has a drawing");
+    let moves: Vec<_> = moves
+        .lines()
+        .filter_map(|line| {
+            let mut words = line.split(' ').skip(1).step_by(2);
+            Some((words.next()?, words.next()?, words.next()?))
+        })
+        .collect();
+    let mut result = format!("{drawing}\n\n");
+    for _ in 0..times {
+        for (count, from, to) in &moves {
+            writeln!(result, "move {count} from {from} to {to}").unwrap();
+        }
+        for (count, from, to) in moves.iter().rev() {
+            writeln!(result, "move {count} from {to} to {from}").unwrap();
+        }
+    }
+    result
+}
+
+/// Terminal transcript of a directory tree `depth` levels deep with `width` entries each.
+///
+/// File sizes are picked so a tree of width 6 and depth 4 fills the disk far enough for
+/// part 2 to need a cleanup.
+fn transcript(width: usize, depth: usize) -> String {
+    fn list(result: &mut String, width: usize, depth: usize) {
+        writeln!(result, "$ ls").unwrap();
+        for index in 0..width {
+            writeln!(result, "{} file{index}.dat", 4000 + index * 200).unwrap();
+        }
+        if depth == 0 {
+            return;
+        }
+        for index in 0..width {
+            writeln!(result, "dir d{index}").unwrap();
+        }
+        for index in 0..width {
+            writeln!(result, "$ cd d{index}").unwrap();
+            list(result, width, depth - 1);
+            writeln!(result, "$ cd ..").unwrap();
+        }
+    }
+
+    let mut result = "$ cd /\n".to_owned();
+    list(&mut result, width, depth);
+    result
+}
+
+/// Tiles the grid `times` by `times`, keeping every row the same length.
+fn tile_grid(input: &str, times: usize) -> String {
+    let rows: Vec<_> = input.lines().filter(|line| !line.is_empty()).collect();
+    let rows: Vec<_> = rows.iter().map(|row| row.repeat(times)).collect();
+    vec![rows.join("\n"); times].join("\n")
+}
+
+fn days(c: &mut Criterion) {
+    let input = real_input(1);
+    let large = vec![input.trim_end(); SCALE].join("\n\n");
+    bench_day::<exc_01::Day01>(c, &[("real", input), ("large", large)]);
+
+    for (day, bench) in [
+        (
+            2,
+            bench_day::<exc_02::Day02> as fn(&mut Criterion, &[(&str, String)]),
+        ),
+        (3, bench_day::<exc_03::Day03>),
+        (4, bench_day::<exc_04::Day04>),
+        (9, bench_day::<exc_09::Day09>),
+    ] {
+        let input = real_input(day);
+        let large = repeat_lines(&input, SCALE);
+        bench(c, &[("real", input), ("large", large)]);
+    }
+
+    let input = real_input(5);
+    let large = reversible_moves(&input, SCALE);
+    bench_day::<exc_05::Day05>(c, &[("real", input), ("large", large)]);
+
+    let input = real_input(6);
+    let large = input.trim().repeat(SCALE);
+    bench_day::<exc_06::Day06>(c, &[("real", input), ("large", large)]);
+
+    let input = real_input(7);
+    let large = transcript(6, 4);
+    bench_day::<exc_07::Day07>(c, &[("real", input), ("large", large)]);
+
+    let input = real_input(8);
+    let large = tile_grid(&input, 4);
+    bench_day::<exc_08::Day08>(c, &[("real", input), ("large", large)]);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,43 @@
+//! Fingerprints the sources of every day, so cached answers of an edited solver are
+//! never reused.
+
+use std::{env, fs, path::Path};
+
+#[path = "src/fingerprint.rs"]
+#[allow(dead_code)]
+mod fingerprint;
+
+fn main() {
+    let manifest = env::var("CARGO_MANIFEST_DIR").expect("cargo sets the manifest dir");
+    let workspace = Path::new(&manifest)
+        .parent()
+        .expect("aoc crate lives inside the workspace");
+
+    let mut days: Vec<String> = fs::read_dir(workspace)
+        .expect("workspace is readable")
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| name.starts_with("exc_"))
+        .collect();
+    days.sort();
+
+    // new days are registered in the manifest
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    let mut generated = String::from("pub const FINGERPRINTS: &[(&str, u64)] = &[\n");
+    for name in days.iter().map(String::as_str).chain(["aoc_common"]) {
+        let dir = workspace.join(name);
+        println!("cargo:rerun-if-changed={}", dir.join("src").display());
+        println!(
+            "cargo:rerun-if-changed={}",
+            dir.join("Cargo.toml").display()
+        );
+    }
+    for name in &days {
+        let fingerprint = fingerprint::fingerprint_day(workspace, name)
+            .unwrap_or_else(|err| panic!("unable to fingerprint {name}: {err}"));
+        generated.push_str(&format!("    ({name:?}, {fingerprint:#018x}),\n"));
+    }
+    generated.push_str("];\n");
+
+    let out = env::var("OUT_DIR").expect("cargo sets the out dir");
+    fs::write(Path::new(&out).join("fingerprints.rs"), generated).expect("out dir is writable");
+}
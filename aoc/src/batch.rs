@@ -0,0 +1,214 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use aoc_common::{Answers, Error, Expected, Params, Part, Result, Source};
+
+use crate::{
+    parallel,
+    verify::{self, Verification},
+    Day,
+};
+
+/// Runs taking longer than this many times the median run are flagged as slow.
+pub const SLOW_FACTOR: u32 = 3;
+
+/// Files of a day's `resources` that are not puzzle inputs.
+const NOT_INPUTS: &[&str] = &["config", "example", "submissions"];
+
+/// Outcome of solving one input of a batch.
+#[derive(Debug)]
+pub struct Entry {
+    pub path: PathBuf,
+    pub result: Result<Answers>,
+    /// Comparison with the answers stored next to the input, empty if there are none.
+    pub verifications: Vec<Verification>,
+    /// Whether the run took much longer than the median run, see [`SLOW_FACTOR`].
+    pub slow: bool,
+}
+
+impl Entry {
+    pub fn is_failure(&self) -> bool {
+        self.result.is_err()
+            || self
+                .verifications
+                .iter()
+                .any(|verification| verification.status.is_failure())
+    }
+}
+
+/// The puzzle inputs in `dir`, sorted by name.
+///
+/// Hidden files and the other files of a day's `resources`, like its answers, are
+/// skipped, so the `resources` of any checkout can be passed as it is.
+pub fn inputs(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut inputs = Vec::new();
+    for entry in fs::read_dir(dir).map_err(|err| Error::io(dir, err))? {
+        let path = entry.map_err(|err| Error::io(dir, err))?.path();
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let skipped = name.starts_with('.')
+            || name.ends_with("answers")
+            || NOT_INPUTS.contains(&name.as_str());
+        if path.is_file() && !skipped {
+            inputs.push(path);
+        }
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+/// Location of the accepted answers for `input`: `answers` next to an `input` file,
+/// `NAME_answers` next to any other file `NAME`.
+pub fn answers_path(input: &Path) -> PathBuf {
+    match input.file_name().and_then(|name| name.to_str()) {
+        Some("input") | None => input.with_file_name("answers"),
+        Some(name) => input.with_file_name(format!("{name}_answers")),
+    }
+}
+
+/// Solves `part` of `day`, or both parts, for every input in `dir` on up to `jobs` threads.
+pub fn run(
+    day: &Day,
+    dir: &Path,
+    part: Option<Part>,
+    params: &Params,
+    jobs: usize,
+) -> Result<Vec<Entry>> {
+    let inputs = inputs(dir)?;
+    if inputs.is_empty() {
+        return Err(Error::Usage(format!(
+            "Directory {} holds no inputs.",
+            dir.display()
+        )));
+    }
+    let results = parallel::map(&inputs, jobs, |path| {
+        day.solve(&Source::File(path.clone()), part, params)
+    });
+    let elapsed: Vec<_> = results
+        .iter()
+        .map(|result| result.as_ref().ok().map(|answers| answers.elapsed))
+        .collect();
+    let slow = flag_slow(&elapsed);
+
+    Ok(inputs
+        .into_iter()
+        .zip(results)
+        .zip(slow)
+        .map(|((path, result), slow)| {
+            let answers_path = answers_path(&path);
+            // unreadable answers fail their entry only, not the whole batch
+            let (result, verifications) = match result {
+                Ok(answers) if answers_path.is_file() => match Expected::load(&answers_path) {
+                    Ok(expected) => {
+                        let verifications = verify::compare(day.number(), &expected, &answers)
+                            .into_iter()
+                            .filter(|verification| answers.output(verification.part).is_some())
+                            .collect();
+                        (Ok(answers), verifications)
+                    }
+                    Err(err) => (Err(err), Vec::new()),
+                },
+                result => (result, Vec::new()),
+            };
+            Entry {
+                path,
+                result,
+                verifications,
+                slow,
+            }
+        })
+        .collect())
+}
+
+/// Flags the durations longer than [`SLOW_FACTOR`] times the median, failed runs are
+/// `None` and never flagged.
+pub fn flag_slow(elapsed: &[Option<Duration>]) -> Vec<bool> {
+    let mut sorted: Vec<Duration> = elapsed.iter().flatten().copied().collect();
+    sorted.sort();
+    let Some(median) = sorted.get(sorted.len() / 2) else {
+        return vec![false; elapsed.len()];
+    };
+    let limit = *median * SLOW_FACTOR;
+    elapsed
+        .iter()
+        .map(|elapsed| elapsed.is_some_and(|elapsed| elapsed > limit))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, path::Path, process, time::Duration};
+
+    use aoc_common::{Error, Params};
+
+    use crate::{
+        batch::{answers_path, flag_slow, inputs, run},
+        find_day,
+        verify::Status,
+    };
+
+    #[test]
+    fn flags_slow_runs() {
+        let ms = |ms| Some(Duration::from_millis(ms));
+        assert_eq!(
+            flag_slow(&[ms(10), ms(12), None, ms(40), ms(9)]),
+            [false, false, false, true, false]
+        );
+        assert_eq!(flag_slow(&[None]), [false]);
+        assert_eq!(
+            answers_path(Path::new("resources/input")),
+            Path::new("resources/answers")
+        );
+        assert_eq!(
+            answers_path(Path::new("inputs/alice")),
+            Path::new("inputs/alice_answers")
+        );
+    }
+
+    #[test]
+    fn solves_every_input_of_a_directory() {
+        let dir = env::temp_dir().join(format!("aoc-batch-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("alice"), "2-4,6-8\n2-8,3-7\n").unwrap();
+        fs::write(dir.join("alice_answers"), "part1: 1\npart2: 1\n").unwrap();
+        fs::write(dir.join("bob"), "2-4,6-8\n2-4;6-8\n").unwrap();
+        fs::write(dir.join("input"), "6-6,4-6\n").unwrap();
+        fs::write(dir.join("answers"), "part1: 2\n").unwrap();
+        fs::write(dir.join("carol"), "2-4,6-8\n").unwrap();
+        fs::write(dir.join("carol_answers"), "part1 = 0\n").unwrap();
+        fs::write(dir.join("example"), "2-4,6-8\n").unwrap();
+        fs::write(dir.join(".hidden"), "").unwrap();
+
+        let names: Vec<_> = inputs(&dir)
+            .unwrap()
+            .into_iter()
+            .map(|path| path.file_name().unwrap().to_owned())
+            .collect();
+        assert_eq!(names, ["alice", "bob", "carol", "input"]);
+
+        let day = find_day(4).unwrap();
+        let entries = run(day, &dir, None, &Params::default(), 2).unwrap();
+        assert!(!entries[0].is_failure());
+        assert_eq!(entries[0].verifications.len(), 2);
+        assert!(matches!(entries[1].result, Err(Error::Parse(_))));
+        assert!(entries[1].is_failure());
+        // a malformed answers file fails its own entry only
+        assert!(matches!(entries[2].result, Err(Error::Parse(_))));
+        assert!(entries[3].is_failure());
+        assert_eq!(
+            entries[3].verifications[0].status,
+            Status::Fail {
+                expected: "2".to_owned(),
+                actual: "1".to_owned()
+            }
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,37 @@
+use std::process;
+
+use aoc::serve::{Server, DEFAULT_MAX_BODY};
+use aoc_common::LogArgs;
+use clap::Parser;
+
+#[derive(Parser)]
+#[command(
+    name = "serve",
+    about = "Solves puzzle inputs posted to /day/{n}/part/{p} and replies with JSON"
+)]
+struct Cli {
+    /// Address to listen on.
+    #[arg(long, default_value = "127.0.0.1:3000")]
+    addr: String,
+    /// Largest accepted input in bytes.
+    #[arg(long, default_value_t = DEFAULT_MAX_BODY)]
+    max_body: usize,
+    #[command(flatten)]
+    log: LogArgs,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    cli.log.init();
+
+    match Server::bind(&cli.addr, cli.max_body) {
+        Ok(server) => {
+            eprintln!("Listening on http://{}", cli.addr);
+            server.run();
+        }
+        Err(err) => {
+            eprintln!("Error: {err}");
+            process::exit(err.exit_code());
+        }
+    }
+}
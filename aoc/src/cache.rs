@@ -0,0 +1,310 @@
+//! Answers of earlier runs, so unchanged inputs are not solved again.
+//!
+//! Entries are keyed by the input, the requested part, the resolved configuration and the
+//! [`Day::fingerprint`], so editing a day or `aoc_common` invalidates its entries.
+
+use std::{
+    fs,
+    io::{self, BufRead},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use aoc_common::{
+    serde_json::{self, json, Map, Value},
+    tracing::{debug, warn},
+    Answers, Error, Output, Params, Part, Result, Source,
+};
+
+use crate::{fingerprint::Fnv, workspace_root, Day};
+
+/// A directory holding one JSON file of answers per key.
+#[derive(Debug, Clone)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Cache { dir: dir.into() }
+    }
+
+    /// The cache below the workspace's `target` directory, cleared by `cargo clean`.
+    pub fn in_workspace() -> Self {
+        Cache::new(workspace_root().join("target").join("answer-cache"))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Key of the answers to `part` of `day` for `input`, computed with the configuration
+    /// described by `config` by sources with the given `fingerprint`.
+    ///
+    /// The input is hashed while it is read, so it is never held in memory at once.
+    pub fn key(
+        day: u8,
+        fingerprint: u64,
+        part: Option<Part>,
+        config: &str,
+        input: &mut dyn BufRead,
+    ) -> io::Result<String> {
+        let mut hash = Fnv::default();
+        hash.write_field(&fingerprint.to_le_bytes());
+        hash.write_field(
+            part.map(|part| part.to_string())
+                .unwrap_or_default()
+                .as_bytes(),
+        );
+        hash.write_field(config.as_bytes());
+        hash.write_field_from(input)?;
+        Ok(format!("day{day:02}-{:016x}", hash.finish()))
+    }
+
+    /// The answers stored under `key`, a missing or unreadable entry is a miss.
+    pub fn get(&self, key: &str) -> Option<Answers> {
+        let path = self.path(key);
+        let content = fs::read_to_string(&path).ok()?;
+        let answers = serde_json::from_str(&content)
+            .ok()
+            .and_then(|value| from_json(&value));
+        if answers.is_none() {
+            debug!(path = %path.display(), "ignoring corrupt cache entry");
+        }
+        answers
+    }
+
+    pub fn put(&self, key: &str, answers: &Answers) -> Result<()> {
+        fs::create_dir_all(&self.dir).map_err(|err| Error::io(&self.dir, err))?;
+        let path = self.path(key);
+        fs::write(&path, to_json(answers).to_string()).map_err(|err| Error::io(&path, err))
+    }
+
+    /// Solves like [`Day::solve`], but takes the answers from the cache if the same input
+    /// was solved before with the same sources and configuration. Also returns whether the
+    /// answers came from the cache.
+    ///
+    /// Input from stdin is always solved, it can be read only once and hashing it first
+    /// would mean holding all of it in memory.
+    pub fn solve(
+        &self,
+        day: &Day,
+        source: &Source,
+        part: Option<Part>,
+        params: &Params,
+    ) -> Result<(Answers, bool)> {
+        let (Some(fingerprint), Source::File(path)) = (day.fingerprint(), source) else {
+            return Ok((day.solve(source, part, params)?, false));
+        };
+        let config = day.describe_config(params)?;
+        let key = Cache::key(
+            day.number(),
+            fingerprint,
+            part,
+            &config,
+            &mut source.open()?,
+        )
+        .map_err(|err| Error::io(path, err))?;
+
+        if let Some(answers) = self.get(&key) {
+            debug!(day = day.number(), key, "answers taken from the cache");
+            return Ok((answers, true));
+        }
+        let answers = day.solve(source, part, params)?;
+        if let Err(err) = self.put(&key, &answers) {
+            warn!(%err, "unable to cache answers");
+        }
+        Ok((answers, false))
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.json"))
+    }
+}
+
+fn to_json(answers: &Answers) -> Value {
+    let output = |output: &Option<Output>| match output {
+        Some(output) => json!({
+            "answer": output.answer,
+            "details": output.details,
+            "elapsed_ns": output.elapsed.as_nanos() as u64,
+        }),
+        None => Value::Null,
+    };
+    json!({
+        "day": answers.day,
+        "part1": output(&answers.part1),
+        "part2": output(&answers.part2),
+        "parse_ns": answers.parse.as_nanos() as u64,
+        "elapsed_ns": answers.elapsed.as_nanos() as u64,
+    })
+}
+
+fn from_json(value: &Value) -> Option<Answers> {
+    let duration = |value: &Value| value.as_u64().map(Duration::from_nanos);
+    let output = |value: &Value| -> Option<Option<Output>> {
+        if value.is_null() {
+            return Some(None);
+        }
+        let answer = match &value["answer"] {
+            Value::Null => None,
+            answer => Some(answer.as_str()?.to_owned()),
+        };
+        let details: Map<String, Value> = value["details"].as_object()?.clone();
+        Some(Some(Output {
+            answer,
+            details,
+            elapsed: duration(&value["elapsed_ns"])?,
+        }))
+    };
+    Some(Answers {
+        day: u8::try_from(value["day"].as_u64()?).ok()?,
+        part1: output(&value["part1"])?,
+        part2: output(&value["part2"])?,
+        parse: duration(&value["parse_ns"])?,
+        elapsed: duration(&value["elapsed_ns"])?,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        env, fs,
+        path::{Path, PathBuf},
+        process,
+    };
+
+    use aoc_common::{Params, Part, Source};
+
+    use crate::{cache::Cache, find_day, fingerprint::fingerprint_day, workspace_root};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-cache-{name}-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn copy_dir(from: &Path, to: &Path) {
+        fs::create_dir_all(to).unwrap();
+        for entry in fs::read_dir(from).unwrap() {
+            let path = entry.unwrap().path();
+            let target = to.join(path.file_name().unwrap());
+            if path.is_dir() {
+                copy_dir(&path, &target);
+            } else {
+                fs::copy(&path, &target).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn reuses_answers_of_unchanged_inputs() {
+        let dir = temp_dir("reuse");
+        let cache = Cache::new(dir.join("cache"));
+        let input = dir.join("input");
+        write(&input, "1\n2\n\n3\n\n4\n");
+        let day = find_day(1).unwrap();
+        let source = Source::File(input.clone());
+        let params = Params::default();
+
+        let (solved, cached) = cache.solve(day, &source, None, &params).unwrap();
+        assert!(!cached);
+        let (reused, cached) = cache.solve(day, &source, None, &params).unwrap();
+        assert!(cached);
+        assert_eq!(reused.get(Part::One), solved.get(Part::One));
+        assert_eq!(reused.get(Part::Two), solved.get(Part::Two));
+        assert_eq!(reused.part1.unwrap().details, solved.part1.unwrap().details);
+        assert_eq!(reused.elapsed, solved.elapsed);
+
+        // parameters set to the original values describe the same configuration
+        let mut original = Params::default();
+        original.set("top=3").unwrap();
+        let (_, cached) = cache.solve(day, &source, None, &original).unwrap();
+        assert!(cached);
+
+        // another part, another configuration or an edited input are solved again
+        let (_, cached) = cache.solve(day, &source, Some(Part::One), &params).unwrap();
+        assert!(!cached);
+        let mut top = Params::default();
+        top.set("top=2").unwrap();
+        let (answers, cached) = cache.solve(day, &source, None, &top).unwrap();
+        assert!(!cached);
+        assert_eq!(answers.get(Part::Two), Some("7"));
+        write(&input, "1\n2\n\n3\n\n5\n");
+        let (answers, cached) = cache.solve(day, &source, None, &params).unwrap();
+        assert!(!cached);
+        assert_eq!(answers.get(Part::One), Some("5"));
+
+        // a corrupt entry is a miss and gets replaced
+        for entry in fs::read_dir(cache.dir()).unwrap() {
+            fs::write(entry.unwrap().path(), "{").unwrap();
+        }
+        let (_, cached) = cache.solve(day, &source, None, &params).unwrap();
+        assert!(!cached);
+        let (_, cached) = cache.solve(day, &source, None, &params).unwrap();
+        assert!(cached);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn editing_a_solver_forces_recomputation() {
+        let workspace = temp_dir("edit");
+        for name in ["exc_09", "aoc_common"] {
+            let (from, to) = (workspace_root().join(name), workspace.join(name));
+            copy_dir(&from.join("src"), &to.join("src"));
+            fs::copy(from.join("Cargo.toml"), to.join("Cargo.toml")).unwrap();
+        }
+        let day = find_day(9).unwrap();
+        let built = day.fingerprint().unwrap();
+        assert_eq!(fingerprint_day(&workspace, "exc_09").unwrap(), built);
+
+        let cache = Cache::new(workspace.join("cache"));
+        let input = workspace.join("input");
+        write(&input, day.example().input);
+        let source = Source::File(input.clone());
+        let params = Params::default();
+        let config = day.describe_config(&params).unwrap();
+        let key = |fingerprint| {
+            let content = fs::read(&input).unwrap();
+            Cache::key(9, fingerprint, None, &config, &mut content.as_slice()).unwrap()
+        };
+        let (_, cached) = cache.solve(day, &source, None, &params).unwrap();
+        assert!(!cached);
+        assert!(cache.get(&key(built)).is_some());
+
+        // a change to how the tail follows the head, like a fix of the solver
+        let solver = workspace.join("exc_09/src/lib.rs");
+        let source_code = fs::read_to_string(&solver).unwrap();
+        assert!(source_code.contains("chebyshev(self.tail) > 1"));
+        write(
+            &solver,
+            &source_code.replace("chebyshev(self.tail) > 1", "chebyshev(self.tail) > 2"),
+        );
+        let edited = fingerprint_day(&workspace, "exc_09").unwrap();
+        assert_ne!(edited, built);
+        assert!(cache.get(&key(edited)).is_none());
+
+        let geometry = workspace.join("aoc_common/src/geometry.rs");
+        let source_code = fs::read_to_string(&geometry).unwrap();
+        write(&geometry, &format!("{source_code}\n"));
+        assert_ne!(fingerprint_day(&workspace, "exc_09").unwrap(), edited);
+
+        write(&workspace.join("exc_09/src/rope.rs"), "");
+        let added = fingerprint_day(&workspace, "exc_09").unwrap();
+        fs::rename(
+            workspace.join("exc_09/src/rope.rs"),
+            workspace.join("exc_09/src/knot.rs"),
+        )
+        .unwrap();
+        assert_ne!(fingerprint_day(&workspace, "exc_09").unwrap(), added);
+
+        fs::remove_dir_all(&workspace).unwrap();
+    }
+}
@@ -0,0 +1,436 @@
+use std::{
+    fmt::Display,
+    panic,
+    path::PathBuf,
+    sync::{
+        mpsc::{self, Sender},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use aoc_common::{format_duration, serde_json, Answers, Expected, Params, Part, Result, Source};
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Paragraph, Row, Table, TableState, Wrap},
+    DefaultTerminal, Frame,
+};
+
+use crate::{
+    parallel,
+    verify::{self, Status, Verification},
+    workspace_root, Day, DAYS,
+};
+
+/// How long to wait for a key press before checking for finished runs again.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Which input of a day gets solved.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum InputKind {
+    /// The personal puzzle input in `resources/input`.
+    #[default]
+    Real,
+    /// The sample from the puzzle text embedded into the day's crate.
+    Example,
+}
+
+impl InputKind {
+    pub fn path(self, day: &Day) -> PathBuf {
+        match self {
+            InputKind::Real => day.input_path(),
+            InputKind::Example => day.example_path(),
+        }
+    }
+
+    fn toggle(self) -> Self {
+        match self {
+            InputKind::Real => InputKind::Example,
+            InputKind::Example => InputKind::Real,
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            InputKind::Real => 0,
+            InputKind::Example => 1,
+        }
+    }
+}
+
+impl Display for InputKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputKind::Real => f.write_str("real"),
+            InputKind::Example => f.write_str("example"),
+        }
+    }
+}
+
+/// Answers of a finished run, checked against the accepted or the example's answers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub answers: Answers,
+    pub verifications: Vec<Verification>,
+}
+
+/// Progress of solving one input of a day.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum RunState {
+    #[default]
+    Idle,
+    Running,
+    Done(Run),
+    Failed(String),
+}
+
+/// Solves `kind` of input of `day`, a panicking solver only fails this run.
+pub fn solve(day: &Day, kind: InputKind) -> Result<Run> {
+    let mut results = parallel::map(&[kind], 1, |kind| {
+        // accepted answers belong to the original puzzle parameters
+        let (answers, expected) = match kind {
+            InputKind::Real => (
+                day.solve(&Source::File(day.input_path()), None, &Params::default())?,
+                Expected::load(&day.answers_path())?,
+            ),
+            InputKind::Example => (
+                day.solve_example(None, &Params::default())?,
+                day.example().expected()?,
+            ),
+        };
+        let verifications = verify::compare(day.number(), &expected, &answers);
+        Ok(Run {
+            answers,
+            verifications,
+        })
+    });
+    results.remove(0)
+}
+
+/// State of the dashboard, separate from the terminal so it can be tested.
+#[derive(Debug)]
+pub struct Dashboard {
+    selected: usize,
+    input: InputKind,
+    /// Last run of every day on the real and on the example input.
+    runs: Vec<[RunState; 2]>,
+}
+
+impl Default for Dashboard {
+    fn default() -> Self {
+        Dashboard {
+            selected: 0,
+            input: InputKind::default(),
+            runs: DAYS.iter().map(|_| Default::default()).collect(),
+        }
+    }
+}
+
+impl Dashboard {
+    pub fn selected(&self) -> &'static Day {
+        &DAYS[self.selected]
+    }
+
+    pub fn input(&self) -> InputKind {
+        self.input
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1) % DAYS.len();
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = (self.selected + DAYS.len() - 1) % DAYS.len();
+    }
+
+    pub fn toggle_input(&mut self) {
+        self.input = self.input.toggle();
+    }
+
+    /// Last run of the day at `index` on the currently shown input.
+    pub fn state(&self, index: usize) -> &RunState {
+        &self.runs[index][self.input.index()]
+    }
+
+    /// Marks the day at `index` as running on the shown input, `None` if it runs already.
+    pub fn start(&mut self, index: usize) -> Option<(usize, InputKind)> {
+        let state = &mut self.runs[index][self.input.index()];
+        if *state == RunState::Running {
+            return None;
+        }
+        *state = RunState::Running;
+        Some((index, self.input))
+    }
+
+    pub fn finish(&mut self, index: usize, kind: InputKind, result: Result<Run>) {
+        self.runs[index][kind.index()] = match result {
+            Ok(run) => RunState::Done(run),
+            Err(err) => RunState::Failed(err.to_string()),
+        };
+    }
+
+    pub fn render(&self, frame: &mut Frame) {
+        let [days, output, help] = Layout::vertical([
+            Constraint::Length(DAYS.len() as u16 + 3),
+            Constraint::Min(3),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let rows = DAYS.iter().enumerate().map(|(index, day)| {
+            let (part1, part2, time, check) = match self.state(index) {
+                RunState::Idle => (String::new(), String::new(), String::new(), "-"),
+                RunState::Running => ("running".to_owned(), String::new(), String::new(), "-"),
+                RunState::Done(run) => (
+                    answer(&run.answers, Part::One),
+                    answer(&run.answers, Part::Two),
+                    format_duration(run.answers.elapsed),
+                    check(&run.verifications),
+                ),
+                RunState::Failed(_) => ("error".to_owned(), String::new(), String::new(), "ERROR"),
+            };
+            let style = match check {
+                "pass" => Style::default().fg(Color::Green),
+                "FAIL" | "ERROR" => Style::default().fg(Color::Red),
+                _ => Style::default(),
+            };
+            Row::new([
+                day.number().to_string(),
+                part1,
+                part2,
+                time,
+                check.to_owned(),
+            ])
+            .style(style)
+        });
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(3),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Length(10),
+                Constraint::Length(7),
+            ],
+        )
+        .header(
+            Row::new(["Day", "Part 1", "Part 2", "Time", "Check"])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .block(Block::bordered().title(format!(" Advent of Code, {} input ", self.input)));
+        let mut table_state = TableState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(table, days, &mut table_state);
+
+        let day = self.selected();
+        let path = self.input.path(day);
+        let path = path.strip_prefix(workspace_root()).unwrap_or(&path);
+        let pane = Paragraph::new(self.output())
+            .wrap(Wrap { trim: false })
+            .block(Block::bordered().title(format!(" Day {}, {} ", day.number(), path.display())));
+        frame.render_widget(pane, output);
+
+        frame.render_widget(
+            Line::from("up/down select  enter run  a run all  tab real/example input  q quit")
+                .style(Style::default().fg(Color::DarkGray)),
+            help,
+        );
+    }
+
+    /// Lines shown in the output pane for the selected day.
+    fn output(&self) -> Vec<Line<'static>> {
+        let run = match self.state(self.selected) {
+            RunState::Idle => return vec![Line::from("Not run yet, press enter to solve it.")],
+            RunState::Running => return vec![Line::from("Solving...")],
+            RunState::Failed(message) => return vec![Line::from(format!("Error: {message}"))],
+            RunState::Done(run) => run,
+        };
+
+        let mut lines = vec![Line::from(format!(
+            "parse: {}",
+            format_duration(run.answers.parse)
+        ))];
+        for part in [Part::One, Part::Two] {
+            let Some(output) = run.answers.output(part) else {
+                continue;
+            };
+            let status = run
+                .verifications
+                .iter()
+                .find(|verification| verification.part == part)
+                .map(|verification| format!("  {}", verification.status))
+                .unwrap_or_default();
+            lines.push(Line::from(format!(
+                "part {part}: {output} ({}){status}",
+                format_duration(output.elapsed)
+            )));
+            if !output.details.is_empty() {
+                let details = serde_json::to_string_pretty(&output.details).unwrap_or_default();
+                lines.extend(details.lines().map(|line| Line::from(format!("  {line}"))));
+            }
+        }
+        lines
+    }
+}
+
+fn answer(answers: &Answers, part: Part) -> String {
+    answers
+        .output(part)
+        .map(ToString::to_string)
+        .unwrap_or_default()
+}
+
+/// Summary of the verifications of both parts for the table.
+fn check(verifications: &[Verification]) -> &'static str {
+    if verifications.iter().any(|v| v.status.is_failure()) {
+        "FAIL"
+    } else if verifications.iter().all(|v| v.status == Status::Pass) {
+        "pass"
+    } else {
+        "partial"
+    }
+}
+
+/// Solves the day at `index` in the background, reporting the result on `results`.
+fn spawn(job: (usize, InputKind), results: &Sender<(usize, InputKind, Result<Run>)>) {
+    let results = results.clone();
+    thread::spawn(move || {
+        let (index, kind) = job;
+        // the receiver is only gone once the dashboard is closed
+        let _ = results.send((index, kind, solve(&DAYS[index], kind)));
+    });
+}
+
+/// Shows the dashboard in the terminal until it is closed with `q`.
+///
+/// Panics of the solvers are shown in the output pane, so they are not reported on stderr
+/// across the screen while the dashboard runs.
+pub fn dashboard() -> Result<()> {
+    let mut terminal = ratatui::init();
+    let ui = thread::current().id();
+    let hook = Arc::new(panic::take_hook());
+    panic::set_hook(Box::new({
+        let hook = Arc::clone(&hook);
+        move |info| {
+            if thread::current().id() == ui {
+                hook(info);
+            }
+        }
+    }));
+    let result = event_loop(&mut terminal);
+    drop(panic::take_hook());
+    panic::set_hook(Box::new(move |info| hook(info)));
+    ratatui::restore();
+    result
+}
+
+fn event_loop(terminal: &mut DefaultTerminal) -> Result<()> {
+    let (sender, receiver) = mpsc::channel();
+    let mut dashboard = Dashboard::default();
+    loop {
+        while let Ok((index, kind, result)) = receiver.try_recv() {
+            dashboard.finish(index, kind, result);
+        }
+        terminal.draw(|frame| dashboard.render(frame))?;
+
+        if !event::poll(POLL_INTERVAL)? {
+            continue;
+        }
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Down | KeyCode::Char('j') => dashboard.select_next(),
+            KeyCode::Up | KeyCode::Char('k') => dashboard.select_previous(),
+            KeyCode::Tab | KeyCode::Char('e') => dashboard.toggle_input(),
+            KeyCode::Enter | KeyCode::Char('r') => {
+                if let Some(job) = dashboard.start(dashboard.selected) {
+                    spawn(job, &sender);
+                }
+            }
+            KeyCode::Char('a') => {
+                for index in 0..DAYS.len() {
+                    if let Some(job) = dashboard.start(index) {
+                        spawn(job, &sender);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::Error;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    use crate::{
+        dashboard::{solve, Dashboard, InputKind, RunState},
+        DAYS,
+    };
+
+    #[test]
+    fn switches_days_and_inputs() {
+        let mut dashboard = Dashboard::default();
+        dashboard.select_previous();
+        assert_eq!(dashboard.selected().number(), DAYS.last().unwrap().number());
+        dashboard.select_next();
+        dashboard.toggle_input();
+        assert_eq!(dashboard.input(), InputKind::Example);
+
+        let job = dashboard.start(0).unwrap();
+        assert_eq!(job, (0, InputKind::Example));
+        assert_eq!(dashboard.start(0), None);
+        dashboard.finish(0, job.1, solve(&DAYS[0], job.1));
+        let RunState::Done(run) = dashboard.state(0) else {
+            panic!("day 1 is solved");
+        };
+        assert_eq!(run.answers.get(aoc_common::Part::Two), Some("45000"));
+
+        // the real input keeps its own state
+        dashboard.toggle_input();
+        assert_eq!(dashboard.state(0), &RunState::Idle);
+    }
+
+    #[test]
+    fn renders_answers_and_checks() {
+        let mut dashboard = Dashboard::default();
+        dashboard.start(0);
+        dashboard.finish(0, InputKind::Real, solve(&DAYS[0], InputKind::Real));
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal.draw(|frame| dashboard.render(frame)).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(screen.contains("Advent of Code, real input"));
+        assert!(screen.contains("pass"));
+        assert!(screen.contains("part 1:"));
+        assert!(screen.contains("exc_01/resources/input"));
+
+        // a panicking solver only fails its run and shows why
+        dashboard.finish(0, InputKind::Real, Err(Error::Panic("boom".to_owned())));
+        terminal.draw(|frame| dashboard.render(frame)).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(screen.contains("Error: Solver panicked: boom"));
+    }
+}
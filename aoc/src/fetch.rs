@@ -0,0 +1,169 @@
+use std::{fmt::Display, fs};
+
+use aoc_common::{Error, Result};
+
+use crate::{
+    http::{HttpBackend, Response},
+    Day, YEAR,
+};
+
+/// Base URL of the puzzle website.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Authenticated access to the puzzle website through an [`HttpBackend`].
+pub struct Client<B> {
+    backend: B,
+    base_url: String,
+    session: String,
+}
+
+impl<B: HttpBackend> Client<B> {
+    pub fn new(backend: B, base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Client {
+            backend,
+            base_url: base_url.into(),
+            session: session.into(),
+        }
+    }
+
+    /// Sends a `GET` request for `path` below the base URL.
+    pub(crate) fn get(&self, path: &str) -> Result<(String, Response)> {
+        let url = format!("{}{path}", self.base_url);
+        let response = self.backend.get(&url, &self.session)?;
+        Ok((url, response))
+    }
+
+    /// Posts `form` to `path` below the base URL.
+    pub(crate) fn post_form(
+        &self,
+        path: &str,
+        form: &[(&str, &str)],
+    ) -> Result<(String, Response)> {
+        let url = format!("{}{path}", self.base_url);
+        let response = self.backend.post_form(&url, &self.session, form)?;
+        Ok((url, response))
+    }
+
+    /// Downloads the personal puzzle input of `day`.
+    pub fn input(&self, day: u8) -> Result<String> {
+        let (url, Response { status, body }) = self.get(&format!("/{YEAR}/day/{day}/input"))?;
+        match status {
+            200 => Ok(body),
+            // the site answers 400 without a valid session and 500 for a malformed one
+            400 | 401 | 403 | 500 => Err(expired_session()),
+            404 => Err(Error::remote(format!(
+                "Day {day} of {YEAR} is not released yet."
+            ))),
+            status => Err(unexpected_status(&url, status, &body)),
+        }
+    }
+}
+
+pub(crate) fn expired_session() -> Error {
+    Error::remote(
+        "Session token was rejected, it has probably expired. \
+         Log in again and copy the new `session` cookie.",
+    )
+}
+
+pub(crate) fn unexpected_status(url: &str, status: u16, body: &str) -> Error {
+    Error::remote(format!(
+        "Unexpected status {status} from {url}: {}",
+        body.trim()
+    ))
+}
+
+/// What [`fetch_day`] did for a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was present already and left untouched.
+    Cached,
+    /// The input was downloaded and stored.
+    Downloaded,
+}
+
+impl Display for Fetched {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Fetched::Cached => f.write_str("cached"),
+            Fetched::Downloaded => f.write_str("downloaded"),
+        }
+    }
+}
+
+/// Stores the input of `day` at its usual `resources/input` location, unless it exists.
+///
+/// The client is only created when a download is necessary, so cached days work without
+/// a session token.
+pub fn fetch_day<B, F>(day: &Day, force: bool, client: F) -> Result<Fetched>
+where
+    B: HttpBackend,
+    F: FnOnce() -> Result<Client<B>>,
+{
+    let path = day.input_path();
+    if !force && path.is_file() {
+        return Ok(Fetched::Cached);
+    }
+
+    let input = client()?.input(day.number())?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|err| Error::io(parent, err))?;
+    }
+    fs::write(&path, input).map_err(|err| Error::io(&path, err))?;
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::Result;
+
+    use crate::{
+        fetch::{fetch_day, Client, Fetched},
+        find_day,
+        http::{stub, UreqBackend},
+    };
+
+    #[test]
+    fn skips_cached_input() {
+        let day = find_day(1).unwrap();
+        let fetched = fetch_day(day, false, || -> Result<Client<UreqBackend>> {
+            panic!("cached input must not be downloaded")
+        });
+        assert_eq!(fetched.unwrap(), Fetched::Cached);
+    }
+
+    #[test]
+    fn downloads_input() {
+        let (url, server) = stub::serve(vec![(200, "1000\n2000\n")]);
+        let client = Client::new(UreqBackend::new(), url, "secret");
+        assert_eq!(client.input(1).unwrap(), "1000\n2000\n");
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2022/day/1/input");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=secret"));
+    }
+
+    #[test]
+    fn reports_expired_session_and_locked_days() {
+        let (url, server) = stub::serve(vec![
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+        ]);
+        let client = Client::new(UreqBackend::new(), url, "expired");
+
+        let err = client.input(1).unwrap_err();
+        assert!(err.to_string().starts_with("Session token was rejected"));
+        assert_eq!(err.exit_code(), 6);
+
+        let err = client.input(25).unwrap_err();
+        assert_eq!(err.to_string(), "Day 25 of 2022 is not released yet.");
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,94 @@
+//! Stable hashes of source files and inputs, shared with the build script.
+//!
+//! The standard library's hashers may change between Rust releases, these hashes are
+//! stored on disk and must not.
+
+use std::{
+    fs,
+    io::{self, BufRead},
+    path::Path,
+};
+
+/// 64-bit FNV-1a hash, fed incrementally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fnv(u64);
+
+impl Default for Fnv {
+    fn default() -> Self {
+        Fnv(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv {
+    pub fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    /// Writes `bytes` followed by its length, so consecutive fields can't run into each
+    /// other.
+    pub fn write_field(&mut self, bytes: &[u8]) {
+        self.write(bytes);
+        self.write(&(bytes.len() as u64).to_le_bytes());
+    }
+
+    /// Like [`Fnv::write_field`], but streams the field from `reader` instead of holding it
+    /// in memory.
+    pub fn write_field_from(&mut self, reader: &mut dyn BufRead) -> io::Result<()> {
+        let mut length = 0_u64;
+        loop {
+            let chunk = reader.fill_buf()?;
+            if chunk.is_empty() {
+                break;
+            }
+            self.write(chunk);
+            let consumed = chunk.len();
+            length += consumed as u64;
+            reader.consume(consumed);
+        }
+        self.write(&length.to_le_bytes());
+        Ok(())
+    }
+
+    pub fn finish(self) -> u64 {
+        self.0
+    }
+}
+
+/// Hashes the names and contents of all files below `dir`, in a stable order.
+pub fn hash_dir(dir: &Path, hash: &mut Fnv) -> io::Result<()> {
+    let mut entries = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            hash_dir(&path, hash)?;
+        } else {
+            hash_file(&path, hash)?;
+        }
+    }
+    Ok(())
+}
+
+/// Hashes the name and contents of the file at `path`.
+pub fn hash_file(path: &Path, hash: &mut Fnv) -> io::Result<()> {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    hash.write_field(name.as_bytes());
+    hash.write_field(&fs::read(path)?);
+    Ok(())
+}
+
+/// Fingerprint of everything a day's answers are computed from: the sources and manifest
+/// of its crate and of the shared `aoc_common` crate.
+pub fn fingerprint_day(workspace: &Path, crate_name: &str) -> io::Result<u64> {
+    let mut hash = Fnv::default();
+    for name in [crate_name, "aoc_common"] {
+        let dir = workspace.join(name);
+        hash_file(&dir.join("Cargo.toml"), &mut hash)?;
+        hash_dir(&dir.join("src"), &mut hash)?;
+    }
+    Ok(hash.finish())
+}
@@ -0,0 +1,73 @@
+use aoc_common::{Params, Part};
+
+use crate::{
+    verify::{Status, Verification},
+    Day,
+};
+
+/// Solves a generated input of `day` part by part and compares the answers with the
+/// generated ones.
+///
+/// Parts the generator has no answer for, or the day has not solved yet, are reported
+/// as missing rather than failed.
+pub fn check(day: &Day, seed: u64, size: usize) -> Vec<Verification> {
+    let generated = day.generate(seed, size);
+    [Part::One, Part::Two]
+        .into_iter()
+        .map(|part| {
+            let result = (day.solver())(
+                &mut generated.input.as_bytes(),
+                Some(part),
+                &Params::default(),
+            );
+            let status = match (generated.answer(part), result) {
+                (Some(expected), Ok(answers)) => match answers.get(part) {
+                    Some(actual) if actual == expected => Status::Pass,
+                    Some(actual) => Status::Fail {
+                        expected: expected.to_owned(),
+                        actual: actual.to_owned(),
+                    },
+                    None => Status::Missing {
+                        actual: "unsolved".to_owned(),
+                    },
+                },
+                (Some(_), Err(err)) => Status::Error(err.to_string()),
+                (None, Ok(answers)) => Status::Missing {
+                    actual: answers.get(part).unwrap_or("unsolved").to_owned(),
+                },
+                (None, Err(err)) => Status::Missing {
+                    actual: err.to_string(),
+                },
+            };
+            Verification {
+                day: day.number(),
+                part,
+                status,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{generate::check, DAYS};
+
+    #[test]
+    fn solves_generated_inputs() {
+        for day in DAYS {
+            for (seed, size) in [(1, 1), (2, 10), (3, 200)] {
+                let generated = day.generate(seed, size);
+                assert_eq!(generated, day.generate(seed, size));
+                for verification in check(day, seed, size) {
+                    assert!(
+                        !verification.status.is_failure(),
+                        "day {} part {} with seed {seed} and size {size}: {}",
+                        verification.day,
+                        verification.part,
+                        verification.status
+                    );
+                }
+            }
+        }
+    }
+}
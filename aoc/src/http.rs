@@ -0,0 +1,178 @@
+use std::io::Read;
+
+use aoc_common::{Error, Result};
+
+/// Status and body of an HTTP response, whatever the status code is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Minimal HTTP client needed to talk to the puzzle website.
+///
+/// Error statuses are returned as a [`Response`] so callers can tell them apart, only
+/// failures to reach the server at all are reported as [`Error::Remote`].
+pub trait HttpBackend {
+    /// Sends a `GET` request to `url`, authenticated with the `session` cookie.
+    fn get(&self, url: &str, session: &str) -> Result<Response>;
+
+    /// Sends a `POST` request with a url-encoded `form` to `url`, authenticated with the
+    /// `session` cookie.
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response>;
+}
+
+/// [`HttpBackend`] doing real requests with `ureq`.
+#[derive(Debug)]
+pub struct UreqBackend {
+    agent: ureq::Agent,
+}
+
+impl Default for UreqBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UreqBackend {
+    pub fn new() -> Self {
+        UreqBackend {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(concat!("aoc/", env!("CARGO_PKG_VERSION")))
+                .build(),
+        }
+    }
+}
+
+impl HttpBackend for UreqBackend {
+    fn get(&self, url: &str, session: &str) -> Result<Response> {
+        let request = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={session}"));
+        into_response(url, request.call())
+    }
+
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response> {
+        let request = self
+            .agent
+            .post(url)
+            .set("Cookie", &format!("session={session}"));
+        into_response(url, request.send_form(form))
+    }
+}
+
+fn into_response(url: &str, result: Result<ureq::Response, ureq::Error>) -> Result<Response> {
+    let response = match result {
+        Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+        Err(err) => return Err(Error::remote(format!("Request to {url} failed: {err}"))),
+    };
+    let status = response.status();
+    let mut body = String::new();
+    response
+        .into_reader()
+        .read_to_string(&mut body)
+        .map_err(|err| Error::remote(format!("Unable to read response of {url}: {err}")))?;
+    Ok(Response { status, body })
+}
+
+/// Single threaded HTTP server answering requests with canned responses.
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    /// A request as received by the stub.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Request {
+        pub method: String,
+        pub path: String,
+        pub cookie: Option<String>,
+        pub body: String,
+    }
+
+    /// Serves one `(status, body)` response per incoming request, then shuts down.
+    ///
+    /// Returns the base URL of the server and a handle yielding the received requests.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<Request>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let mut parts = line.split_whitespace();
+                let method = parts.next().unwrap_or_default().to_owned();
+                let path = parts.next().unwrap_or_default().to_owned();
+
+                let mut cookie = None;
+                let mut length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    let header = header.trim_end();
+                    if header.is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = header.split_once(':') {
+                        match name.to_ascii_lowercase().as_str() {
+                            "cookie" => cookie = Some(value.trim().to_owned()),
+                            "content-length" => length = value.trim().parse().unwrap(),
+                            _ => {}
+                        }
+                    }
+                }
+                let mut request_body = vec![0; length];
+                reader.read_exact(&mut request_body).unwrap();
+                requests.push(Request {
+                    method,
+                    path,
+                    cookie,
+                    body: String::from_utf8(request_body).unwrap(),
+                });
+
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (url, handle)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::http::{stub, HttpBackend, Response, UreqBackend};
+
+    #[test]
+    fn returns_error_statuses_as_response() {
+        let (url, server) = stub::serve(vec![(200, "1\n2\n"), (404, "Not yet")]);
+        let backend = UreqBackend::new();
+
+        let response = backend.get(&format!("{url}/ok"), "abc").unwrap();
+        assert_eq!(
+            response,
+            Response {
+                status: 200,
+                body: "1\n2\n".to_owned()
+            }
+        );
+        let response = backend.get(&format!("{url}/missing"), "abc").unwrap();
+        assert_eq!(response.status, 404);
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].path, "/ok");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=abc"));
+    }
+}
@@ -0,0 +1,209 @@
+//! Registry of all solved days, used by the `aoc` dispatcher binary.
+
+use std::path::{Path, PathBuf};
+
+use aoc_common::{
+    Answers, DescribeFn, Example, GenerateFn, Generated, Generator, Params, Part, Result, Solver,
+    Source,
+};
+
+pub mod batch;
+pub mod cache;
+pub mod dashboard;
+pub mod fetch;
+pub mod fingerprint;
+pub mod generate;
+pub mod http;
+pub mod parallel;
+pub mod scaffold;
+#[cfg(feature = "serve")]
+pub mod serve;
+pub mod submit;
+pub mod verify;
+pub mod watch;
+
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/fingerprints.rs"));
+}
+
+/// Year of the advent calendar solved in this workspace.
+pub const YEAR: u16 = 2022;
+
+/// A day of the advent calendar known to the dispatcher.
+pub struct Day {
+    number: u8,
+    solver: Solver,
+    describe_config: DescribeFn,
+    generator: GenerateFn,
+    example: Example,
+}
+
+impl Day {
+    const fn of<S: Generator>() -> Self {
+        Day {
+            number: S::DAY,
+            solver: aoc_common::solve::<S>,
+            describe_config: aoc_common::describe_config::<S>,
+            generator: aoc_common::generate::<S>,
+            example: S::EXAMPLE,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
+    pub fn solver(&self) -> Solver {
+        self.solver
+    }
+
+    /// The day's configuration for `params`, see [`aoc_common::describe_config`].
+    pub fn describe_config(&self, params: &Params) -> Result<String> {
+        (self.describe_config)(params)
+    }
+
+    /// Hash of the sources the day's answers are computed from, taken when the dispatcher
+    /// was built, see [`fingerprint::fingerprint_day`].
+    pub fn fingerprint(&self) -> Option<u64> {
+        let name = self.crate_name();
+        generated::FINGERPRINTS
+            .iter()
+            .find(|(crate_name, _)| *crate_name == name)
+            .map(|(_, fingerprint)| *fingerprint)
+    }
+
+    /// Synthetic input of about `size` records, the same `seed` yields the same input.
+    pub fn generate(&self, seed: u64, size: usize) -> Generated {
+        (self.generator)(seed, size)
+    }
+
+    /// Name of the workspace crate holding this day, e.g. `exc_05`.
+    pub fn crate_name(&self) -> String {
+        format!("exc_{:02}", self.number)
+    }
+
+    /// Reads the input from `source` and solves the requested `part`, or both parts if none
+    /// is given.
+    pub fn solve(&self, source: &Source, part: Option<Part>, params: &Params) -> Result<Answers> {
+        (self.solver)(&mut source.open()?, part, params).map_err(|err| err.in_file(source.name()))
+    }
+
+    /// The worked example of the puzzle text, embedded into the day's crate.
+    pub fn example(&self) -> Example {
+        self.example
+    }
+
+    /// Solves the requested `part` of the embedded example, or both parts if none is given.
+    pub fn solve_example(&self, part: Option<Part>, params: &Params) -> Result<Answers> {
+        (self.solver)(&mut self.example.input.as_bytes(), part, params)
+            .map_err(|err| err.in_file(self.example_path()))
+    }
+
+    /// Parameters set in the day's optional `resources/config` file.
+    pub fn params(&self) -> Result<Params> {
+        Params::load(&self.config_path())
+    }
+
+    /// Location of the puzzle input committed alongside the day's crate.
+    pub fn input_path(&self) -> PathBuf {
+        self.resources_path().join("input")
+    }
+
+    /// Location of the sample input given in the puzzle text, see [`Day::example`].
+    pub fn example_path(&self) -> PathBuf {
+        self.resources_path().join("example")
+    }
+
+    /// Location of the accepted answers for the day's puzzle input.
+    pub fn answers_path(&self) -> PathBuf {
+        self.resources_path().join("answers")
+    }
+
+    /// Location of the puzzle parameters used instead of the original ones.
+    pub fn config_path(&self) -> PathBuf {
+        self.resources_path().join("config")
+    }
+
+    /// Location of the history of answers submitted for the day's puzzle.
+    pub fn submissions_path(&self) -> PathBuf {
+        self.resources_path().join("submissions")
+    }
+
+    fn resources_path(&self) -> PathBuf {
+        workspace_root().join(self.crate_name()).join("resources")
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::of::<exc_01::Day01>(),
+    Day::of::<exc_02::Day02>(),
+    Day::of::<exc_03::Day03>(),
+    Day::of::<exc_04::Day04>(),
+    Day::of::<exc_05::Day05>(),
+    Day::of::<exc_06::Day06>(),
+    Day::of::<exc_07::Day07>(),
+    Day::of::<exc_08::Day08>(),
+    Day::of::<exc_09::Day09>(),
+];
+
+pub fn find_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+/// Root directory of the workspace the dispatcher was built in.
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc crate lives inside the workspace")
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::Params;
+
+    use std::collections::HashSet;
+
+    use crate::{find_day, fingerprint::fingerprint_day, verify::compare, workspace_root, DAYS};
+
+    #[test]
+    fn registers_days_in_order() {
+        let numbers: Vec<_> = DAYS.iter().map(|day| day.number()).collect();
+        assert_eq!(numbers.first(), Some(&1));
+        assert!(
+            numbers.windows(2).all(|pair| pair[0] < pair[1]),
+            "{numbers:?}"
+        );
+    }
+
+    #[test]
+    fn locates_day_input() {
+        let day = find_day(5).unwrap();
+        assert_eq!(day.crate_name(), "exc_05");
+        assert!(day.input_path().ends_with("exc_05/resources/input"));
+        assert!(day.input_path().exists());
+    }
+
+    #[test]
+    fn solves_embedded_examples() {
+        for day in DAYS {
+            let answers = day.solve_example(None, &Params::default()).unwrap();
+            let expected = day.example().expected().unwrap();
+            for verification in compare(day.number(), &expected, &answers) {
+                assert!(!verification.status.is_failure(), "{verification:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn fingerprints_match_the_sources() {
+        // the build script reruns whenever a day's sources change, so the fingerprints it
+        // baked in must be those of the sources as they are now
+        let mut fingerprints = HashSet::new();
+        for day in DAYS {
+            let current = fingerprint_day(workspace_root(), &day.crate_name()).unwrap();
+            assert_eq!(day.fingerprint(), Some(current), "day {}", day.number());
+            fingerprints.insert(current);
+        }
+        assert_eq!(fingerprints.len(), DAYS.len());
+    }
+}
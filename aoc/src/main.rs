@@ -0,0 +1,550 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, Instant},
+};
+
+use aoc::{
+    batch,
+    cache::Cache,
+    dashboard,
+    fetch::{self, Client},
+    find_day, generate,
+    http::UreqBackend,
+    parallel, scaffold, submit, verify, watch, Day, DAYS,
+};
+use aoc_common::{
+    format_duration, serde_json::json, Answers, Error, Format, LogArgs, ParamArgs, Params, Part,
+    Result, Source,
+};
+use clap::{Args, Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    #[command(flatten)]
+    log: LogArgs,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves a single day or all days at once.
+    Run(RunArgs),
+    /// Checks the answers for the real inputs against each day's `resources/answers`.
+    Verify(VerifyArgs),
+    /// Downloads the puzzle inputs into each day's `resources/input`.
+    Fetch(FetchArgs),
+    /// Solves a part on the real input and submits the answer.
+    Submit(SubmitArgs),
+    /// Creates the crate for a new day and registers it in the workspace.
+    New(NewArgs),
+    /// Re-runs a day's sample tests and real solve whenever its source or input changes.
+    Watch(WatchArgs),
+    /// Writes a synthetic input with known answers, or checks a day against many of them.
+    Generate(GenerateArgs),
+    /// Shows all days with their answers, run times and checks in an interactive terminal UI.
+    Dashboard,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to solve.
+    #[arg(short, long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+    /// Only solve the given part of the day.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Input file to use instead of the day's `resources/input`, `-` reads from stdin.
+    #[arg(short, long, requires = "day")]
+    input: Option<PathBuf>,
+    /// Solve the worked example of the puzzle text and compare it with its expected answers.
+    ///
+    /// The expected answers belong to the original puzzle parameters, so none can be set.
+    #[arg(long, requires = "day", conflicts_with_all = ["input", "set", "config"])]
+    example: bool,
+    /// Solve every input in a directory, e.g. the `resources` of each team member, and
+    /// compare the answers and timings.
+    #[arg(long, value_name = "DIR", requires = "day", conflicts_with_all = ["input", "example"])]
+    inputs: Option<PathBuf>,
+    /// Solve all days and print a summary table.
+    #[arg(long)]
+    all: bool,
+    /// Output format of the answers.
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+    /// Report how long parsing and each part took.
+    #[arg(long)]
+    time: bool,
+    /// Number of days or inputs solved concurrently with `--all` or `--inputs`.
+    #[arg(short, long, default_value_t = 1)]
+    jobs: usize,
+    /// Solve again even if the answers for the same input and sources are cached.
+    #[arg(long)]
+    no_cache: bool,
+    /// Puzzle parameters of the day, on top of its `resources/config`.
+    #[command(flatten)]
+    params: ParamArgs,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify the given day instead of all days.
+    #[arg(short, long)]
+    day: Option<u8>,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Only fetch the given day instead of all days.
+    #[arg(short, long)]
+    day: Option<u8>,
+    /// Download the input even if it is stored already.
+    #[arg(long)]
+    force: bool,
+    #[command(flatten)]
+    session: SessionArgs,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day to submit.
+    #[arg(short, long)]
+    day: u8,
+    /// Part to submit.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    #[command(flatten)]
+    session: SessionArgs,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to create.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    /// Day to watch.
+    #[arg(short, long)]
+    day: u8,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// Day to generate an input for.
+    #[arg(short, long)]
+    day: u8,
+    /// Seed of the random number generator, the same seed yields the same input.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// Number of records of the input, e.g. lines, elves or grid rows.
+    #[arg(long, default_value_t = 1000)]
+    size: usize,
+    /// File to write the input to instead of stdout.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    /// File to write the known answers to instead of stderr, in `resources/answers` format.
+    #[arg(long)]
+    answers: Option<PathBuf>,
+    /// Solve generated inputs and compare the answers instead of writing an input.
+    #[arg(long, conflicts_with_all = ["output", "answers"])]
+    check: bool,
+    /// Number of consecutive seeds to check, starting at `--seed`.
+    #[arg(long, default_value_t = 1, requires = "check")]
+    runs: u64,
+}
+
+#[derive(Args)]
+struct SessionArgs {
+    /// Value of the `session` cookie of the puzzle website.
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+    /// Base URL of the puzzle website.
+    #[arg(long, env = "AOC_URL", default_value = fetch::BASE_URL, hide = true)]
+    url: String,
+}
+
+impl SessionArgs {
+    fn client(&self) -> Result<Client<UreqBackend>> {
+        let session = self.session.as_deref().ok_or_else(|| {
+            Error::Usage("A session token is required, pass --session or set AOC_SESSION.".into())
+        })?;
+        Ok(Client::new(UreqBackend::new(), self.url.as_str(), session))
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+    cli.log.init();
+
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Fetch(args) => fetch(args).map(|_| true),
+        Command::Submit(args) => submit(args),
+        Command::New(args) => new(args).map(|_| true),
+        Command::Watch(args) => watch(args).map(|_| true),
+        Command::Generate(args) => generate(args),
+        Command::Dashboard => dashboard::dashboard().map(|_| true),
+    };
+    match result {
+        Ok(true) => {}
+        // failed verifications are not errors of the tool itself
+        Ok(false) => process::exit(1),
+        Err(err) => {
+            eprintln!("Error: {err}");
+            process::exit(err.exit_code());
+        }
+    }
+}
+
+fn run(args: RunArgs) -> Result<bool> {
+    let part = args
+        .part
+        .map(|part| Part::try_from(part).expect("part is validated by clap"));
+
+    match args.day {
+        Some(number) => {
+            let day = find_day(number)
+                .ok_or_else(|| Error::Usage(format!("Day {number} is not solved yet.")))?;
+            if args.example {
+                let answers = day.solve_example(part, &Params::default())?;
+                let expected = day.example().expected()?;
+                return Ok(aoc_common::print_example(&answers, &expected, args.format));
+            }
+            let params = args.params.apply(day.params()?)?;
+            if let Some(dir) = &args.inputs {
+                let entries = batch::run(day, dir, part, &params, args.jobs)?;
+                match args.format {
+                    Format::Text => print_batch(&entries, dir),
+                    Format::Json => print_batch_json(&entries),
+                }
+                return Ok(!entries.iter().any(batch::Entry::is_failure));
+            }
+            let source = Source::from(args.input.unwrap_or_else(|| day.input_path()));
+            let (answers, cached) = solve(day, &source, part, &params, args.no_cache)?;
+            match args.format {
+                Format::Text => {
+                    for part in [Part::One, Part::Two] {
+                        if let Some(output) = answers.output(part) {
+                            println!("Day {number} part {part}: {output}");
+                        }
+                    }
+                    if args.time {
+                        aoc_common::print_timings(&answers);
+                        if cached {
+                            eprintln!(
+                                "Timings of the cached run, pass --no-cache to measure again."
+                            );
+                        }
+                    }
+                }
+                Format::Json => aoc_common::print_answers(&answers, Format::Json),
+            }
+            Ok(true)
+        }
+        None => {
+            if !args.params.set.is_empty() || args.params.config.is_some() {
+                return Err(Error::Usage(
+                    "Puzzle parameters can only be set for a single day.".into(),
+                ));
+            }
+            let start = Instant::now();
+            let answers = parallel::map(DAYS, args.jobs, |day| {
+                let source = Source::File(day.input_path());
+                solve(day, &source, part, &day.params()?, args.no_cache)
+            });
+            let wall_clock = start.elapsed();
+            let cached = answers
+                .iter()
+                .filter(|result| matches!(result, Ok((_, true))))
+                .count();
+            let answers = answers
+                .into_iter()
+                .map(|result| result.map(|(answers, _)| answers));
+            let results: Vec<_> = DAYS.iter().zip(answers).collect();
+            match args.format {
+                Format::Text => {
+                    print_summary(&results, args.time, wall_clock);
+                    if cached > 0 {
+                        eprintln!(
+                            "{cached} of {} days taken from the cache, pass --no-cache to solve them again.",
+                            DAYS.len()
+                        );
+                    }
+                }
+                Format::Json => print_json(&results),
+            }
+            // report the first failure once every day had its chance to run
+            match results.into_iter().find_map(|(_, result)| result.err()) {
+                Some(err) => Err(err),
+                None => Ok(true),
+            }
+        }
+    }
+}
+
+/// Solves `day` for `source`, reusing the cached answers unless `no_cache` is set.
+fn solve(
+    day: &Day,
+    source: &Source,
+    part: Option<Part>,
+    params: &Params,
+    no_cache: bool,
+) -> Result<(Answers, bool)> {
+    if no_cache {
+        Ok((day.solve(source, part, params)?, false))
+    } else {
+        Cache::in_workspace().solve(day, source, part, params)
+    }
+}
+
+fn verify(args: VerifyArgs) -> Result<bool> {
+    let days = select_days(args.day)?;
+
+    let mut passed = true;
+    for day in days {
+        for verification in verify::verify_day(day) {
+            println!(
+                "Day {:>2} part {}: {}",
+                verification.day, verification.part, verification.status
+            );
+            passed &= !verification.status.is_failure();
+        }
+    }
+    Ok(passed)
+}
+
+fn fetch(args: FetchArgs) -> Result<()> {
+    let days = select_days(args.day)?;
+    for day in days {
+        let fetched = fetch::fetch_day(day, args.force, || args.session.client())?;
+        println!("Day {:>2}: {fetched}", day.number());
+    }
+    Ok(())
+}
+
+fn submit(args: SubmitArgs) -> Result<bool> {
+    let day = find_day(args.day)
+        .ok_or_else(|| Error::Usage(format!("Day {} is not solved yet.", args.day)))?;
+    let part = Part::try_from(args.part).expect("part is validated by clap");
+    let attempt = submit::submit_day(day, part, &args.session.client()?)?;
+    println!(
+        "Day {} part {part}: {} is {}",
+        day.number(),
+        attempt.answer,
+        attempt.verdict
+    );
+    Ok(attempt.verdict == submit::Verdict::Correct)
+}
+
+fn new(args: NewArgs) -> Result<()> {
+    for path in scaffold::scaffold(aoc::workspace_root(), args.day)? {
+        println!("{}", path.display());
+    }
+    Ok(())
+}
+
+fn watch(args: WatchArgs) -> Result<()> {
+    let day = find_day(args.day)
+        .ok_or_else(|| Error::Usage(format!("Day {} is not solved yet.", args.day)))?;
+    watch::watch(day)
+}
+
+fn generate(args: GenerateArgs) -> Result<bool> {
+    let day = find_day(args.day)
+        .ok_or_else(|| Error::Usage(format!("Day {} is not solved yet.", args.day)))?;
+
+    if args.check {
+        let mut passed = true;
+        for seed in args.seed..args.seed.saturating_add(args.runs) {
+            for verification in generate::check(day, seed, args.size) {
+                if verification.status.is_failure() {
+                    println!(
+                        "Day {:>2} part {} seed {seed}: {}",
+                        verification.day, verification.part, verification.status
+                    );
+                    passed = false;
+                }
+            }
+        }
+        if passed {
+            println!(
+                "Day {:>2}: {} generated inputs pass",
+                day.number(),
+                args.runs
+            );
+        }
+        return Ok(passed);
+    }
+
+    let generated = day.generate(args.seed, args.size);
+    match &args.output {
+        Some(path) => fs::write(path, &generated.input).map_err(|err| Error::io(path, err))?,
+        None => print!("{}", generated.input),
+    }
+    let answers: String = [Part::One, Part::Two]
+        .into_iter()
+        .filter_map(|part| Some(format!("part{part}: {}\n", generated.answer(part)?)))
+        .collect();
+    match &args.answers {
+        Some(path) => fs::write(path, answers).map_err(|err| Error::io(path, err))?,
+        None => eprint!("{answers}"),
+    }
+    Ok(true)
+}
+
+/// The requested day, or all days if none is given.
+fn select_days(number: Option<u8>) -> Result<Vec<&'static Day>> {
+    match number {
+        Some(number) => Ok(vec![find_day(number)
+            .ok_or_else(|| Error::Usage(format!("Day {number} is not solved yet.")))?]),
+        None => Ok(DAYS.iter().collect()),
+    }
+}
+
+fn print_summary(results: &[(&Day, Result<Answers>)], time: bool, wall_clock: Duration) {
+    print!(
+        "{:>3}  {:<20}  {:<20}  {:>10}",
+        "Day", "Part 1", "Part 2", "Time"
+    );
+    if time {
+        print!("  {:>10}  {:>10}  {:>10}", "Parse", "Part 1", "Part 2");
+    }
+    println!();
+    for (day, result) in results {
+        match result {
+            Ok(answers) => {
+                print!(
+                    "{:>3}  {:<20}  {:<20}  {:>10}",
+                    answers.day,
+                    format_output(answers, Part::One),
+                    format_output(answers, Part::Two),
+                    format_duration(answers.elapsed)
+                );
+                if time {
+                    print!(
+                        "  {:>10}  {:>10}  {:>10}",
+                        format_duration(answers.parse),
+                        format_elapsed(answers, Part::One),
+                        format_elapsed(answers, Part::Two)
+                    );
+                }
+                println!();
+            }
+            Err(err) => println!("{:>3}  {err}", day.number()),
+        }
+    }
+    let total: Duration = results
+        .iter()
+        .filter_map(|(_, result)| result.as_ref().ok())
+        .map(|answers| answers.elapsed)
+        .sum();
+    println!("{:<47}  {:>10}", "Total", format_duration(total));
+    println!("{:<47}  {:>10}", "Wall clock", format_duration(wall_clock));
+}
+
+/// Prints a row per input of a batch, naming the inputs relative to `dir`.
+fn print_batch(entries: &[batch::Entry], dir: &Path) {
+    println!(
+        "{:<20}  {:<20}  {:<20}  {:>10}  Flags",
+        "Input", "Part 1", "Part 2", "Time"
+    );
+    for entry in entries {
+        let name = entry
+            .path
+            .strip_prefix(dir)
+            .unwrap_or(&entry.path)
+            .display();
+        let mut flags = Vec::new();
+        match &entry.result {
+            Ok(answers) => print!(
+                "{name:<20}  {:<20}  {:<20}  {:>10}",
+                format_output(answers, Part::One),
+                format_output(answers, Part::Two),
+                format_duration(answers.elapsed)
+            ),
+            Err(Error::Panic(message)) => {
+                print!("{name:<20}  {:<54}", "-");
+                flags.push(format!("PANIC {message}"));
+            }
+            Err(err) => {
+                print!("{name:<20}  {:<54}", "-");
+                flags.push(format!("ERROR {err}"));
+            }
+        }
+        if entry.slow {
+            flags.push(format!("SLOW >{}x median", batch::SLOW_FACTOR));
+        }
+        for verification in &entry.verifications {
+            match &verification.status {
+                verify::Status::Fail { expected, .. } => {
+                    flags.push(format!("part {} expected {expected}", verification.part))
+                }
+                verify::Status::Error(message) => {
+                    flags.push(format!("part {} ERROR {message}", verification.part))
+                }
+                _ => {}
+            }
+        }
+        println!("  {}", flags.join(", "));
+    }
+}
+
+/// Prints one JSON object per part and input, failed inputs get a single object with
+/// their error.
+fn print_batch_json(entries: &[batch::Entry]) {
+    for entry in entries {
+        let input = entry.path.display().to_string();
+        match &entry.result {
+            Ok(answers) => {
+                for mut object in answers.to_json() {
+                    object["input"] = json!(input);
+                    object["slow"] = json!(entry.slow);
+                    let part = object["part"].as_u64();
+                    if let Some(verification) = entry
+                        .verifications
+                        .iter()
+                        .find(|verification| Some(u64::from(u8::from(verification.part))) == part)
+                    {
+                        object["status"] = json!(verification.status.to_string());
+                    }
+                    println!("{object}");
+                }
+            }
+            Err(err) => println!(
+                "{}",
+                json!({"input": input, "error": err.to_string(), "panic": matches!(err, Error::Panic(_))})
+            ),
+        }
+    }
+}
+
+/// Prints one JSON object per part, failed days get a single object with their error.
+fn print_json(results: &[(&Day, Result<Answers>)]) {
+    for (day, result) in results {
+        match result {
+            Ok(answers) => aoc_common::print_answers(answers, Format::Json),
+            Err(err) => println!("{}", json!({"day": day.number(), "error": err.to_string()})),
+        }
+    }
+}
+
+fn format_elapsed(answers: &Answers, part: Part) -> String {
+    answers
+        .output(part)
+        .map(|output| format_duration(output.elapsed))
+        .unwrap_or_else(|| "-".to_owned())
+}
+
+fn format_output(answers: &Answers, part: Part) -> String {
+    answers
+        .output(part)
+        .map(ToString::to_string)
+        .unwrap_or_else(|| "-".to_owned())
+}
@@ -0,0 +1,83 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+use aoc_common::{Error, Result};
+
+/// Applies `f` to all `items` on up to `jobs` threads, returning the results in order.
+///
+/// A panic in `f` only fails the result of its item, it's reported as [`Error::Panic`].
+pub fn map<T, R, F>(items: &[T], jobs: usize, f: F) -> Vec<Result<R>>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> Result<R> + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<Result<R>>>> =
+        Mutex::new((0..items.len()).map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                let result = panic::catch_unwind(AssertUnwindSafe(|| f(item)))
+                    .unwrap_or_else(|payload| Err(Error::Panic(panic_message(payload.as_ref()))));
+                results.lock().expect("no panics while holding the lock")[index] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .expect("no panics while holding the lock")
+        .into_iter()
+        .map(|result| result.expect("every item is processed"))
+        .collect()
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_owned()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown cause".to_owned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::Error;
+
+    use crate::parallel::map;
+
+    #[test]
+    fn keeps_order_and_survives_panics() {
+        let items: Vec<u32> = (1..=9).collect();
+        let results = map(&items, 4, |&item| {
+            if item == 5 {
+                panic!("day {item} exploded");
+            }
+            Ok(item * 10)
+        });
+
+        assert_eq!(results.len(), 9);
+        for (item, result) in items.iter().zip(&results) {
+            match result {
+                Ok(value) => assert_eq!(*value, item * 10),
+                Err(Error::Panic(message)) => assert_eq!(message, "day 5 exploded"),
+                Err(err) => panic!("unexpected error {err}"),
+            }
+        }
+        assert!(results[4].is_err());
+    }
+}
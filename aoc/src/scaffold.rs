@@ -0,0 +1,264 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{Error, Result};
+
+/// Creates the crate of a new `day` inside the workspace at `root` and registers it.
+///
+/// Besides the crate itself, the day is added to the workspace members, to the
+/// dependencies of the `aoc` crate and to its [`DAYS`](crate::DAYS). Returns the paths
+/// of all created and modified files.
+pub fn scaffold(root: &Path, day: u8) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::Usage(format!(
+            "Day {day} does not exist, expected 1 to 25."
+        )));
+    }
+    let name = format!("exc_{day:02}");
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(Error::Usage(format!(
+            "{} exists already, refusing to overwrite it.",
+            dir.display()
+        )));
+    }
+
+    // work out all registrations first, so nothing is written if one of them fails
+    let member = format!("  \"{name}\",");
+    let dependency = format!("{name} = {{ path = \"../{name}\" }}");
+    let registration = format!("    Day::of::<{name}::Day{day:02}>(),");
+    let registrations = [
+        (root.join("Cargo.toml"), "  \"exc_", Some("]"), member),
+        (root.join("aoc/Cargo.toml"), "exc_", None, dependency),
+        (
+            root.join("aoc/src/lib.rs"),
+            "    Day::of::<exc_",
+            Some("];"),
+            registration,
+        ),
+    ]
+    .into_iter()
+    .map(|(path, prefix, end, line)| {
+        let content = insert_line(&path, prefix, end, &line)?;
+        Ok((path, content))
+    })
+    .collect::<Result<Vec<_>>>()?;
+
+    let files = [
+        (dir.join("Cargo.toml"), cargo_toml(&name)),
+        (dir.join("src/lib.rs"), lib_rs(day)),
+        (dir.join("src/main.rs"), main_rs(day)),
+        (dir.join("resources/example"), String::new()),
+        (dir.join("resources/example_answers"), String::new()),
+    ];
+    let mut touched = Vec::new();
+    for (path, content) in files.into_iter().chain(registrations) {
+        write(&path, &content)?;
+        touched.push(path);
+    }
+    Ok(touched)
+}
+
+fn write(path: &Path, content: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|err| Error::io(parent, err))?;
+    }
+    fs::write(path, content).map_err(|err| Error::io(path, err))
+}
+
+/// Content of the file at `path` with `line` inserted into the block of lines starting
+/// with `prefix`, keeping the block sorted.
+///
+/// The block ends at the first line not starting with `prefix`. If `end` is given and that
+/// line is a different one, the file does not look as expected.
+fn insert_line(path: &Path, prefix: &str, end: Option<&str>, line: &str) -> Result<String> {
+    let content = fs::read_to_string(path).map_err(|err| Error::io(path, err))?;
+    let mut lines: Vec<&str> = content.lines().collect();
+    let unexpected = || {
+        Error::Usage(format!(
+            "Unable to register the new day in {}, please add `{}` by hand.",
+            path.display(),
+            line.trim()
+        ))
+    };
+
+    let first = lines
+        .iter()
+        .position(|existing| existing.starts_with(prefix))
+        .ok_or_else(unexpected)?;
+    let block = lines[first..]
+        .iter()
+        .take_while(|existing| existing.starts_with(prefix))
+        .count();
+    if end.is_some_and(|end| lines.get(first + block) != Some(&end)) {
+        return Err(unexpected());
+    }
+    let index = first
+        + lines[first..first + block]
+            .iter()
+            .take_while(|existing| **existing < line)
+            .count();
+    lines.insert(index, line);
+
+    let mut content = lines.join("\n");
+    content.push('\n');
+    Ok(content)
+}
+
+fn cargo_toml(name: &str) -> String {
+    format!(
+        r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+authors = [ "Andreas Gerlach" ]
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc_common = {{ path = "../aoc_common" }}
+"#
+    )
+}
+
+fn lib_rs(day: u8) -> String {
+    format!(
+        r#"//! Day {day}.
+
+use aoc_common::{{example, Example, Generated, Generator, Result, Rng, Solution, Unsolved}};
+
+/// Solution of day {day}.
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    const DAY: u8 = {day};
+    const EXAMPLE: Example = example!();
+
+    type Input = Vec<String>;
+    type Config = ();
+    type Part1 = Unsolved;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input> {{
+        Ok(input.lines().map(ToOwned::to_owned).collect())
+    }}
+
+    fn part1(_input: &Self::Input, _config: &Self::Config) -> Result<Self::Part1> {{
+        Ok(Unsolved)
+    }}
+
+    fn part2(_input: &Self::Input, _config: &Self::Config) -> Result<Self::Part2> {{
+        Ok(Unsolved)
+    }}
+}}
+
+impl Generator for Day{day:02} {{
+    fn generate(rng: &mut Rng, size: usize) -> Generated {{
+        let input = (0..size)
+            .map(|_| format!("{{}}\n", rng.range(0..1000)))
+            .collect();
+        Generated {{
+            input,
+            ..Default::default()
+        }}
+    }}
+}}
+
+#[cfg(test)]
+mod test {{
+    use aoc_common::check_example;
+
+    use crate::Day{day:02};
+
+    #[test]
+    fn solves_example() {{
+        check_example::<Day{day:02}>().unwrap();
+    }}
+}}
+"#
+    )
+}
+
+fn main_rs(day: u8) -> String {
+    format!("fn main() {{\n    aoc_common::run::<exc_{day:02}::Day{day:02}>();\n}}\n")
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, path::PathBuf, process};
+
+    use crate::scaffold::scaffold;
+
+    const MEMBERS: &str = r#"[workspace]
+members = [
+  "aoc",
+  "aoc_common",
+  "exc_01",
+  "exc_02",
+]
+"#;
+    const DEPENDENCIES: &str = r#"[package]
+name = "aoc"
+
+[dependencies]
+aoc_common = { path = "../aoc_common" }
+exc_01 = { path = "../exc_01" }
+exc_02 = { path = "../exc_02" }
+ratatui = "~0.29"
+"#;
+    const DAYS: &str = r#"pub const DAYS: &[Day] = &[
+    Day::of::<exc_01::Day01>(),
+    Day::of::<exc_02::Day02>(),
+];
+"#;
+
+    /// A workspace with two days, holding only the files the scaffolder edits, in a fresh
+    /// temporary directory.
+    fn fake_workspace(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{name}-{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        for (file, content) in [
+            ("Cargo.toml", MEMBERS),
+            ("aoc/Cargo.toml", DEPENDENCIES),
+            ("aoc/src/lib.rs", DAYS),
+        ] {
+            fs::write(root.join(file), content).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn creates_and_registers_day() {
+        let root = fake_workspace("new");
+        scaffold(&root, 10).unwrap();
+
+        let main = fs::read_to_string(root.join("exc_10/src/main.rs")).unwrap();
+        assert!(main.contains("aoc_common::run::<exc_10::Day10>();"));
+        assert!(root.join("exc_10/resources/example_answers").is_file());
+
+        let members = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(members.ends_with("  \"exc_02\",\n  \"exc_10\",\n]\n"));
+        let dependencies = fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap();
+        assert!(dependencies.contains(
+            "exc_02 = { path = \"../exc_02\" }\nexc_10 = { path = \"../exc_10\" }\nratatui"
+        ));
+        let days = fs::read_to_string(root.join("aoc/src/lib.rs")).unwrap();
+        assert!(days
+            .ends_with("    Day::of::<exc_02::Day02>(),\n    Day::of::<exc_10::Day10>(),\n];\n"));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn refuses_existing_day() {
+        let root = fake_workspace("existing");
+        fs::create_dir(root.join("exc_02")).unwrap();
+        let err = scaffold(&root, 2).unwrap_err();
+        assert!(err.to_string().ends_with("refusing to overwrite it."));
+        assert_eq!(err.exit_code(), 2);
+        assert!(scaffold(&root, 26).is_err());
+        fs::remove_dir_all(root).unwrap();
+    }
+}
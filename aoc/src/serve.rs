@@ -0,0 +1,254 @@
+use std::{io::Read, net::SocketAddr, thread};
+
+use aoc_common::{
+    serde_json::{json, Value},
+    tracing::{debug, warn},
+    Error, Params, Part, Result,
+};
+
+use crate::{find_day, parallel};
+
+/// Largest input accepted by default, real puzzle inputs stay far below.
+pub const DEFAULT_MAX_BODY: usize = 1024 * 1024;
+
+/// Status and JSON body answering a request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reply {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl Reply {
+    fn error(status: u16, kind: &str, message: impl Into<String>) -> Self {
+        Reply {
+            status,
+            body: json!({ "error": { "kind": kind, "message": message.into() } }),
+        }
+    }
+
+    /// Reply to a failed solve, malformed inputs are located by line and column.
+    fn from_error(err: &Error) -> Self {
+        match err {
+            Error::Parse(err) => Reply {
+                status: 422,
+                body: json!({ "error": {
+                    "kind": "parse",
+                    "message": err.message(),
+                    "line": err.line(),
+                    "column": err.column(),
+                } }),
+            },
+            Error::Domain(message) => Reply::error(422, "domain", message.as_str()),
+            Error::Usage(message) => Reply::error(400, "usage", message.as_str()),
+            Error::Io { .. } => Reply::error(400, "io", err.to_string()),
+            Error::Remote(message) => Reply::error(502, "remote", message.as_str()),
+            Error::Panic(message) => Reply::error(500, "panic", message.as_str()),
+        }
+    }
+}
+
+/// Answers a request, independent of the HTTP server.
+///
+/// `POST /day/{n}/part/{p}` replies with the JSON object of that part, `POST /day/{n}`
+/// with an array holding both parts. The input is solved with the original puzzle
+/// parameters.
+pub fn route(method: &str, url: &str, input: &str) -> Reply {
+    let path = url.split('?').next().unwrap_or_default();
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let (day, part) = match segments.as_slice() {
+        ["day", day] => (*day, None),
+        ["day", day, "part", part] => (*day, Some(*part)),
+        _ => {
+            return Reply::error(
+                404,
+                "not_found",
+                format!("No endpoint at {path}, expected /day/{{n}}/part/{{p}}."),
+            )
+        }
+    };
+    if method != "POST" {
+        return Reply::error(
+            405,
+            "method",
+            format!("Expected POST with the input as body, found {method}."),
+        );
+    }
+    let Some(day) = day.parse().ok().and_then(find_day) else {
+        return Reply::error(404, "not_found", format!("Day {day} is not solved yet."));
+    };
+    let part = match part {
+        None => None,
+        Some(part) => match part
+            .parse::<u8>()
+            .ok()
+            .and_then(|part| Part::try_from(part).ok())
+        {
+            Some(part) => Some(part),
+            None => {
+                return Reply::error(
+                    404,
+                    "not_found",
+                    format!("Part {part} does not exist, expected 1 or 2."),
+                )
+            }
+        },
+    };
+
+    // a panicking solver only fails this request
+    let mut results = parallel::map(&[input], 1, |input| {
+        (day.solver())(&mut input.as_bytes(), part, &Params::default())
+    });
+    match results.remove(0) {
+        Ok(answers) => {
+            let mut parts = answers.to_json();
+            let body = match part {
+                Some(_) => parts.remove(0),
+                None => Value::Array(parts),
+            };
+            Reply { status: 200, body }
+        }
+        Err(err) => Reply::from_error(&err),
+    }
+}
+
+/// Reads the body of `request`, refusing bodies larger than `max_body` bytes.
+fn read_body(request: &mut tiny_http::Request, max_body: usize) -> Result<String, Reply> {
+    let too_large = || {
+        Reply::error(
+            413,
+            "too_large",
+            format!("Input exceeds the limit of {max_body} bytes."),
+        )
+    };
+    if request
+        .body_length()
+        .is_some_and(|length| length > max_body)
+    {
+        return Err(too_large());
+    }
+    let mut body = Vec::new();
+    request
+        .as_reader()
+        .take(max_body as u64 + 1)
+        .read_to_end(&mut body)
+        .map_err(|err| Reply::error(400, "io", format!("Unable to read the input: {err}")))?;
+    if body.len() > max_body {
+        return Err(too_large());
+    }
+    String::from_utf8(body).map_err(|_| Reply::error(400, "io", "Input is not valid UTF-8."))
+}
+
+fn respond(mut request: tiny_http::Request, max_body: usize) {
+    let reply = match read_body(&mut request, max_body) {
+        Ok(input) => route(request.method().as_str(), request.url(), &input),
+        Err(reply) => reply,
+    };
+    debug!(
+        method = %request.method(),
+        url = request.url(),
+        status = reply.status,
+        "answered request"
+    );
+    let header =
+        tiny_http::Header::from_bytes("Content-Type", "application/json").expect("header is valid");
+    let response = tiny_http::Response::from_string(reply.body.to_string())
+        .with_status_code(reply.status)
+        .with_header(header);
+    if let Err(err) = request.respond(response) {
+        warn!(%err, "unable to send response");
+    }
+}
+
+/// HTTP server solving the inputs posted to it, see [`route`].
+pub struct Server {
+    server: tiny_http::Server,
+    max_body: usize,
+}
+
+impl Server {
+    /// Listens on `addr`, port `0` picks a free one.
+    pub fn bind(addr: &str, max_body: usize) -> Result<Self> {
+        let server = tiny_http::Server::http(addr)
+            .map_err(|err| Error::Usage(format!("Unable to listen on {addr}: {err}")))?;
+        Ok(Server { server, max_body })
+    }
+
+    pub fn addr(&self) -> Option<SocketAddr> {
+        self.server.server_addr().to_ip()
+    }
+
+    /// Answers requests, each on its own thread, until [`Server::stop`] is called.
+    pub fn run(&self) {
+        for request in self.server.incoming_requests() {
+            let max_body = self.max_body;
+            thread::spawn(move || respond(request, max_body));
+        }
+    }
+
+    pub fn stop(&self) {
+        self.server.unblock();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{sync::Arc, thread};
+
+    use aoc_common::serde_json::{self, Value};
+
+    use crate::serve::Server;
+
+    /// Posts `input` to `path` and returns the status and JSON body of the reply.
+    fn post(url: &str, path: &str, input: &str) -> (u16, Value) {
+        let response = match ureq::post(&format!("{url}{path}")).send_string(input) {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+            Err(err) => panic!("request failed: {err}"),
+        };
+        let status = response.status();
+        (
+            status,
+            serde_json::from_reader(response.into_reader()).unwrap(),
+        )
+    }
+
+    #[test]
+    fn solves_posted_inputs() {
+        let server = Arc::new(Server::bind("127.0.0.1:0", 64).unwrap());
+        let url = format!("http://{}", server.addr().unwrap());
+        let handle = thread::spawn({
+            let server = Arc::clone(&server);
+            move || server.run()
+        });
+
+        let (status, body) = post(&url, "/day/4/part/2", "2-4,6-8\n2-6,4-8\n");
+        assert_eq!(status, 200);
+        assert_eq!(body["answer"], "1");
+        assert_eq!(body["part"], 2);
+
+        let (status, body) = post(&url, "/day/2", "A Y\nB X\nC Z\n");
+        assert_eq!(status, 200);
+        assert_eq!(body[0]["answer"], "15");
+        assert_eq!(body[1]["answer"], "12");
+
+        let (status, body) = post(&url, "/day/4/part/1", "2-4,6-8\n2-4;6-8\n");
+        assert_eq!(status, 422);
+        assert_eq!(body["error"]["kind"], "parse");
+        assert_eq!(body["error"]["line"], 2);
+
+        let (status, body) = post(&url, "/day/4/part/1", &"2-4,6-8\n".repeat(10));
+        assert_eq!(status, 413);
+        assert_eq!(body["error"]["kind"], "too_large");
+
+        assert_eq!(post(&url, "/day/25/part/1", "").0, 404);
+        assert_eq!(post(&url, "/day/4/part/3", "").0, 404);
+        assert_eq!(post(&url, "/days", "").0, 404);
+        let status = match ureq::get(&format!("{url}/day/4")).call() {
+            Err(ureq::Error::Status(status, _)) => status,
+            other => panic!("expected an error status, got {other:?}"),
+        };
+        assert_eq!(status, 405);
+
+        server.stop();
+        handle.join().unwrap();
+    }
+}
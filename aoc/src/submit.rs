@@ -0,0 +1,494 @@
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Write},
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_common::{Error, Expected, Params, ParseError, Part, Result, Source};
+
+use crate::{
+    fetch::{expired_session, unexpected_status, Client},
+    http::{HttpBackend, Response},
+    Day, YEAR,
+};
+
+/// How the puzzle website judged a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// The answer was not checked, another one may be sent after the given time.
+    Wait(Duration),
+    /// The part was solved before, the answer was not checked.
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Reads the verdict from the HTML page the website answers a submission with.
+    pub fn from_page(page: &str) -> Option<Self> {
+        if page.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Some(Verdict::TooHigh)
+            } else if page.contains("your answer is too low") {
+                Some(Verdict::TooLow)
+            } else {
+                Some(Verdict::Wrong)
+            }
+        } else if page.contains("You gave an answer too recently") {
+            Some(Verdict::Wait(parse_wait(page).unwrap_or_default()))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Verdict::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    /// Name of the verdict in the submission history, `None` if it is not worth keeping.
+    fn token(&self) -> Option<&'static str> {
+        match self {
+            Verdict::Correct => Some("correct"),
+            Verdict::TooHigh => Some("too-high"),
+            Verdict::TooLow => Some("too-low"),
+            Verdict::Wrong => Some("wrong"),
+            Verdict::AlreadySolved => Some("already-solved"),
+            Verdict::Wait(_) => None,
+        }
+    }
+
+    fn from_token(token: &str) -> Option<Self> {
+        match token {
+            "correct" => Some(Verdict::Correct),
+            "too-high" => Some(Verdict::TooHigh),
+            "too-low" => Some(Verdict::TooLow),
+            "wrong" => Some(Verdict::Wrong),
+            "already-solved" => Some(Verdict::AlreadySolved),
+            _ => None,
+        }
+    }
+}
+
+/// Parses the remaining time of `You have 1m 23s left to wait.`
+fn parse_wait(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+    page[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            let (value, unit) = amount.split_at(amount.len().checked_sub(1)?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => f.write_str("correct"),
+            Verdict::TooHigh => f.write_str("wrong, too high"),
+            Verdict::TooLow => f.write_str("wrong, too low"),
+            Verdict::Wrong => f.write_str("wrong"),
+            Verdict::Wait(duration) => write!(
+                f,
+                "not checked, wait {}s before submitting again",
+                duration.as_secs()
+            ),
+            Verdict::AlreadySolved => f.write_str("not checked, part is solved already"),
+        }
+    }
+}
+
+impl<B: HttpBackend> Client<B> {
+    /// Submits `answer` for `part` of `day`.
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Verdict> {
+        let level = part.to_string();
+        let form = [("level", level.as_str()), ("answer", answer)];
+        let (url, Response { status, body }) =
+            self.post_form(&format!("/{YEAR}/day/{day}/answer"), &form)?;
+        match status {
+            200 => Verdict::from_page(&body).ok_or_else(|| {
+                Error::remote(format!(
+                    "Unable to find the verdict in the answer of {url}."
+                ))
+            }),
+            400 | 401 | 403 | 500 => Err(expired_session()),
+            status => Err(unexpected_status(&url, status, &body)),
+        }
+    }
+}
+
+/// A submission that got checked by the website.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Answers submitted for a day, as recorded in its `resources/submissions` file.
+///
+/// The file holds one `partN: answer verdict` line per submission with a verdict, and a
+/// `wait-until: seconds` line with the Unix time before which the website takes no answer.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct History {
+    attempts: Vec<Attempt>,
+    wait_until: Option<SystemTime>,
+}
+
+impl History {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut history = History::default();
+        for (index, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let invalid = || {
+                ParseError::new(format!("Expected `partN: answer verdict`, found {line}."))
+                    .at_line(index + 1)
+            };
+            let (part, rest) = line.split_once(':').ok_or_else(invalid)?;
+            if part == "wait-until" {
+                let seconds = rest.trim().parse().map_err(|_| invalid())?;
+                history.wait_until = Some(UNIX_EPOCH + Duration::from_secs(seconds));
+                continue;
+            }
+            let (answer, verdict) = rest.trim().rsplit_once(' ').ok_or_else(invalid)?;
+            let part = match part {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                _ => return Err(invalid()),
+            };
+            let verdict = Verdict::from_token(verdict).ok_or_else(invalid)?;
+            history.attempts.push(Attempt {
+                part,
+                answer: answer.to_owned(),
+                verdict,
+            });
+        }
+        Ok(history)
+    }
+
+    /// Loads the history stored at `path`, a missing file means nothing was submitted yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(input) => Ok(History::parse(&input).map_err(|err| err.in_file(path))?),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(History::default()),
+            Err(err) => Err(Error::io(path, err)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_string()).map_err(|err| Error::io(path, err))
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        self.attempts.as_ref()
+    }
+
+    /// Time before which the website takes no answer, if it asked to wait.
+    pub fn wait_until(&self) -> Option<SystemTime> {
+        self.wait_until
+    }
+
+    /// Keeps `attempt` made at `now`, a [`Verdict::Wait`] only keeps the time to wait for.
+    pub fn record(&mut self, attempt: Attempt, now: SystemTime) {
+        match attempt.verdict {
+            Verdict::Wait(duration) => self.wait_until = Some(now + duration),
+            _ => self.attempts.push(attempt),
+        }
+    }
+
+    /// Refuses answers that are known to be wrong, or would not be checked at `now`,
+    /// without asking the website.
+    ///
+    /// Besides answers submitted before, numeric answers are compared against the bounds
+    /// given by earlier `too high` and `too low` verdicts.
+    pub fn check(&self, part: Part, answer: &str, now: SystemTime) -> Result<()> {
+        let refuse = |reason: String| {
+            Err(Error::Usage(format!(
+                "Refusing to submit {answer} for part {part}: {reason}."
+            )))
+        };
+        if let Some(left) = self
+            .wait_until
+            .and_then(|deadline| deadline.duration_since(now).ok())
+            .filter(|left| !left.is_zero())
+        {
+            return refuse(format!(
+                "the website asked to wait another {}s",
+                left.as_secs().max(1)
+            ));
+        }
+        let value = answer.parse::<i64>().ok();
+        for attempt in self.attempts.iter().filter(|attempt| attempt.part == part) {
+            match attempt.verdict {
+                Verdict::Correct => {
+                    return refuse(format!("it is solved already with {}", attempt.answer));
+                }
+                Verdict::AlreadySolved => return refuse("it is solved already".to_owned()),
+                _ => {}
+            }
+            if attempt.answer == answer {
+                return refuse(format!(
+                    "it was submitted before and is {}",
+                    attempt.verdict
+                ));
+            }
+            let bound = attempt.answer.parse::<i64>().ok();
+            match (attempt.verdict, value, bound) {
+                (Verdict::TooHigh, Some(value), Some(bound)) if value >= bound => {
+                    return refuse(format!("{bound} was too high already"));
+                }
+                (Verdict::TooLow, Some(value), Some(bound)) if value <= bound => {
+                    return refuse(format!("{bound} was too low already"));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for History {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(deadline) = self.wait_until {
+            let seconds = deadline.duration_since(UNIX_EPOCH).unwrap_or_default();
+            writeln!(f, "wait-until: {}", seconds.as_secs())?;
+        }
+        for attempt in &self.attempts {
+            let token = attempt.verdict.token().unwrap_or_default();
+            writeln!(f, "part{}: {} {token}", attempt.part, attempt.answer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Solves `part` of `day` on its real input and submits the answer.
+///
+/// Every verdict is added to `resources/submissions`, a correct answer is also stored in
+/// `resources/answers` so `verify` picks it up.
+pub fn submit_day<B: HttpBackend>(day: &Day, part: Part, client: &Client<B>) -> Result<Attempt> {
+    // the website only knows the answers to the original puzzle parameters
+    let answers = day.solve(
+        &Source::File(day.input_path()),
+        Some(part),
+        &Params::default(),
+    )?;
+    let answer = answers.get(part).ok_or_else(|| {
+        Error::domain(format!(
+            "Part {part} of day {} is not solved yet.",
+            day.number()
+        ))
+    })?;
+    submit_answer(
+        client,
+        day.number(),
+        part,
+        answer,
+        &day.submissions_path(),
+        &day.answers_path(),
+    )
+}
+
+/// Submits `answer` unless the history at `submissions` refuses it, and records the
+/// verdict there and a correct answer at `answers`.
+fn submit_answer<B: HttpBackend>(
+    client: &Client<B>,
+    day: u8,
+    part: Part,
+    answer: &str,
+    submissions: &Path,
+    answers: &Path,
+) -> Result<Attempt> {
+    let mut history = History::load(submissions)?;
+    history.check(part, answer, SystemTime::now())?;
+
+    let verdict = client.submit(day, part, answer)?;
+    let attempt = Attempt {
+        part,
+        answer: answer.to_owned(),
+        verdict,
+    };
+    history.record(attempt.clone(), SystemTime::now());
+    history.save(submissions)?;
+
+    if verdict == Verdict::Correct {
+        record_answer(answers, part, answer)?;
+    }
+    Ok(attempt)
+}
+
+/// Adds `answer` to the accepted answers at `path`, unless the part has one already.
+fn record_answer(path: &Path, part: Part, answer: &str) -> Result<()> {
+    if Expected::load(path)?.get(part).is_some() {
+        return Ok(());
+    }
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| writeln!(file, "part{part}: {answer}"))
+        .map_err(|err| Error::io(path, err))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        env, fs, process,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    };
+
+    use aoc_common::{Expected, Part};
+
+    use crate::{
+        fetch::Client,
+        http::{stub, UreqBackend},
+        submit::{submit_answer, Attempt, History, Verdict},
+    };
+
+    const TOO_LOW: &str = "<main><article><p>That's not the right answer; your answer is too \
+                           low.</p></article></main>";
+    const CORRECT: &str = "<main><article><p>That's the right answer!</p></article></main>";
+    const WAIT: &str = "<main><article><p>You gave an answer too recently. You have 1m 23s \
+                        left to wait.</p></article></main>";
+
+    #[test]
+    fn reads_verdicts() {
+        let page = "<article><p>That's not the right answer; your answer is too high. \
+                    Please wait one minute before trying again.</p></article>";
+        assert_eq!(Verdict::from_page(page), Some(Verdict::TooHigh));
+
+        let page = "<article><p>You gave an answer too recently; you have to wait after \
+                    submitting an answer before trying again.  You have 1m 23s left to \
+                    wait.</p></article>";
+        assert_eq!(
+            Verdict::from_page(page),
+            Some(Verdict::Wait(Duration::from_secs(83)))
+        );
+        assert_eq!(Verdict::from_page("<html></html>"), None);
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let now = SystemTime::now();
+        let history = History::parse("part1: 4361 too-high\npart1: 100 too-low\n").unwrap();
+        assert!(history.check(Part::One, "4360", now).is_ok());
+        assert!(history.check(Part::Two, "4361", now).is_ok());
+
+        let err = history.check(Part::One, "4361", now).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Refusing to submit 4361 for part 1: it was submitted before and is wrong, too high."
+        );
+        let err = history.check(Part::One, "5000", now).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Refusing to submit 5000 for part 1: 4361 was too high already."
+        );
+        assert!(history.check(Part::One, "42", now).is_err());
+
+        assert_eq!(
+            history.to_string(),
+            "part1: 4361 too-high\npart1: 100 too-low\n"
+        );
+    }
+
+    #[test]
+    fn waits_as_long_as_asked() {
+        let start = UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let mut history = History::default();
+        history.record(
+            Attempt {
+                part: Part::Two,
+                answer: "36".to_owned(),
+                verdict: Verdict::Wait(Duration::from_secs(83)),
+            },
+            start,
+        );
+        assert!(history.attempts().is_empty());
+        assert_eq!(history.to_string(), "wait-until: 1000083\n");
+        assert_eq!(History::parse(&history.to_string()).unwrap(), history);
+
+        let err = history
+            .check(Part::One, "1", start + Duration::from_secs(80))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Refusing to submit 1 for part 1: the website asked to wait another 3s."
+        );
+        assert!(history
+            .check(Part::One, "1", start + Duration::from_secs(83))
+            .is_ok());
+    }
+
+    #[test]
+    fn submits_answer() {
+        let (url, server) = stub::serve(vec![(
+            200,
+            "<main><article><p>That's the right answer!</p></article></main>",
+        )]);
+        let client = Client::new(UreqBackend::new(), url, "secret");
+        assert_eq!(
+            client.submit(9, Part::Two, "2651").unwrap(),
+            Verdict::Correct
+        );
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2022/day/9/answer");
+        assert_eq!(requests[0].body, "level=2&answer=2651");
+    }
+
+    #[test]
+    fn records_verdicts_of_submissions() {
+        let dir = env::temp_dir().join(format!("aoc-submit-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let (submissions, answers) = (dir.join("submissions"), dir.join("answers"));
+        let (url, server) = stub::serve(vec![(200, TOO_LOW), (200, CORRECT), (200, WAIT)]);
+        let client = Client::new(UreqBackend::new(), url, "secret");
+        let submit = |part, answer| submit_answer(&client, 9, part, answer, &submissions, &answers);
+
+        assert_eq!(submit(Part::One, "100").unwrap().verdict, Verdict::TooLow);
+        let err = submit(Part::One, "100").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Refusing to submit 100 for part 1: it was submitted before and is wrong, too low."
+        );
+        assert_eq!(submit(Part::One, "6354").unwrap().verdict, Verdict::Correct);
+        assert_eq!(
+            Expected::load(&answers).unwrap().get(Part::One),
+            Some("6354")
+        );
+
+        let verdict = submit(Part::Two, "2651").unwrap().verdict;
+        assert_eq!(verdict, Verdict::Wait(Duration::from_secs(83)));
+        let err = submit(Part::Two, "2651").unwrap_err();
+        assert!(
+            err.to_string().contains("the website asked to wait"),
+            "{err}"
+        );
+
+        // refused submissions never reach the website
+        assert_eq!(server.join().unwrap().len(), 3);
+        let history = History::load(&submissions).unwrap();
+        assert_eq!(history.attempts().len(), 2);
+        assert!(history.wait_until().unwrap() > SystemTime::now());
+        assert_eq!(Expected::load(&answers).unwrap().get(Part::Two), None);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
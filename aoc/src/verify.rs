@@ -0,0 +1,144 @@
+use std::fmt::Display;
+
+use aoc_common::{Answers, Expected, Params, Part, Source};
+
+use crate::Day;
+
+/// Outcome of comparing a single part against its accepted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing { actual: String },
+    Error(String),
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Fail { .. } | Status::Error(_))
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => f.write_str("pass"),
+            Status::Fail { expected, actual } => {
+                write!(
+                    f,
+                    "FAIL\n    - expected: {expected}\n    + actual:   {actual}"
+                )
+            }
+            Status::Missing { actual } => write!(f, "missing (actual: {actual})"),
+            Status::Error(message) => write!(f, "ERROR {message}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verification {
+    pub day: u8,
+    pub part: Part,
+    pub status: Status,
+}
+
+/// Compares freshly computed `answers` with the `expected` ones, part by part.
+pub fn compare(day: u8, expected: &Expected, answers: &Answers) -> Vec<Verification> {
+    [Part::One, Part::Two]
+        .into_iter()
+        .map(|part| {
+            let actual = answers
+                .output(part)
+                .map(ToString::to_string)
+                .unwrap_or_default();
+            let status = match expected.get(part) {
+                Some(expected) if expected == actual => Status::Pass,
+                Some(expected) => Status::Fail {
+                    expected: expected.to_owned(),
+                    actual,
+                },
+                None => Status::Missing { actual },
+            };
+            Verification { day, part, status }
+        })
+        .collect()
+}
+
+/// Solves `day` on its real input with the original parameters and checks both parts
+/// against `resources/answers`.
+pub fn verify_day(day: &Day) -> Vec<Verification> {
+    let result = Expected::load(&day.answers_path()).and_then(|expected| {
+        // a freshly created day has neither an input nor answers to check yet
+        if expected == Expected::default() && !day.input_path().exists() {
+            return Ok([Part::One, Part::Two]
+                .into_iter()
+                .map(|part| Verification {
+                    day: day.number(),
+                    part,
+                    status: Status::Missing {
+                        actual: "no input".to_owned(),
+                    },
+                })
+                .collect());
+        }
+        // accepted answers belong to the original puzzle parameters
+        let answers = day.solve(&Source::File(day.input_path()), None, &Params::default())?;
+        Ok(compare(day.number(), &expected, &answers))
+    });
+
+    result.unwrap_or_else(|err| {
+        [Part::One, Part::Two]
+            .into_iter()
+            .map(|part| Verification {
+                day: day.number(),
+                part,
+                status: Status::Error(err.to_string()),
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use aoc_common::{Answers, Expected, Map, Output};
+
+    use crate::verify::{compare, Status};
+
+    #[test]
+    fn compares_answers() {
+        let expected = Expected::parse("part1: 4360\n").unwrap();
+        let answers = Answers {
+            day: 9,
+            part1: Some(Output {
+                answer: Some("4361".to_owned()),
+                details: Map::new(),
+                elapsed: Duration::ZERO,
+            }),
+            part2: Some(Output {
+                answer: None,
+                details: Map::new(),
+                elapsed: Duration::ZERO,
+            }),
+            parse: Duration::ZERO,
+            elapsed: Duration::ZERO,
+        };
+        let statuses: Vec<_> = compare(9, &expected, &answers)
+            .into_iter()
+            .map(|verification| verification.status)
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                Status::Fail {
+                    expected: "4360".to_owned(),
+                    actual: "4361".to_owned()
+                },
+                Status::Missing {
+                    actual: "unsolved".to_owned()
+                }
+            ]
+        );
+    }
+}
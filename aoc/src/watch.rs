@@ -0,0 +1,221 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use aoc_common::{serde_json::Value, Error, Part, Result};
+
+use crate::{workspace_root, Day};
+
+/// How often the watched directories are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Modification time and length of all files below a set of directories.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Snapshot(BTreeMap<PathBuf, (SystemTime, u64)>);
+
+impl Snapshot {
+    /// Records every file below `dirs`, directories that don't exist are skipped.
+    pub fn take(dirs: &[PathBuf]) -> Self {
+        let mut files = BTreeMap::new();
+        let mut pending = dirs.to_vec();
+        while let Some(dir) = pending.pop() {
+            let Ok(entries) = fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                match entry.metadata() {
+                    Ok(metadata) if metadata.is_dir() => pending.push(path),
+                    Ok(metadata) => {
+                        let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                        files.insert(path, (modified, metadata.len()));
+                    }
+                    Err(_) => {}
+                }
+            }
+        }
+        Snapshot(files)
+    }
+}
+
+/// Result of running the sample tests and the real solve once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub tests_passed: bool,
+    /// Answers of both parts, or why the day could not be solved.
+    pub answers: Result<[Option<String>; 2], String>,
+}
+
+impl Report {
+    /// Compact summary, comparing the answers with those of the `previous` run.
+    pub fn summary(&self, previous: Option<&Report>) -> String {
+        let tests = if self.tests_passed { "pass" } else { "FAIL" };
+        let mut summary = format!("tests: {tests}");
+        match &self.answers {
+            Ok(answers) => {
+                for part in [Part::One, Part::Two] {
+                    let index = usize::from(u8::from(part)) - 1;
+                    let answer = answers[index].as_deref().unwrap_or("unsolved");
+                    let before = previous
+                        .and_then(|previous| previous.answers.as_ref().ok())
+                        .map(|answers| answers[index].as_deref().unwrap_or("unsolved"));
+                    match before {
+                        Some(before) if before != answer => {
+                            summary.push_str(&format!("  part {part}: {before} -> {answer}"))
+                        }
+                        _ => summary.push_str(&format!("  part {part}: {answer}")),
+                    }
+                }
+            }
+            Err(err) => summary.push_str(&format!("  solve: {err}")),
+        }
+        summary
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.summary(None))
+    }
+}
+
+/// Runs the sample tests of `day` and then solves its real input, both in a fresh build.
+///
+/// The day is built and run through `cargo`, so changes to its source are picked up
+/// without restarting the watcher.
+pub fn run_once(day: &Day) -> Result<Report> {
+    let crate_name = day.crate_name();
+    let tests_passed = cargo()
+        .args(["test", "--quiet", "-p", &crate_name])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map_err(spawn_error)?
+        .success();
+
+    let output = cargo()
+        .args(["run", "--quiet", "-p", &crate_name, "--"])
+        .arg(day.input_path())
+        .args(["--format", "json"])
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(spawn_error)?;
+    let answers = if output.status.success() {
+        Ok(parse_answers(&String::from_utf8_lossy(&output.stdout)))
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(stderr.lines().last().unwrap_or("failed").to_owned())
+    };
+    Ok(Report {
+        tests_passed,
+        answers,
+    })
+}
+
+fn cargo() -> Command {
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.current_dir(workspace_root());
+    command
+}
+
+fn spawn_error(err: std::io::Error) -> Error {
+    Error::Usage(format!("Unable to run cargo: {err}"))
+}
+
+/// Reads the answers from the JSON lines a day prints with `--format json`.
+fn parse_answers(output: &str) -> [Option<String>; 2] {
+    let mut answers = [None, None];
+    for object in output
+        .lines()
+        .filter_map(|line| aoc_common::serde_json::from_str::<Value>(line).ok())
+    {
+        let index = match object["part"].as_u64() {
+            Some(1) => 0,
+            Some(2) => 1,
+            _ => continue,
+        };
+        answers[index] = object["answer"].as_str().map(ToOwned::to_owned);
+    }
+    answers
+}
+
+/// Re-runs `day` whenever a file below its `src/` or `resources/` changes, forever.
+pub fn watch(day: &Day) -> Result<()> {
+    let crate_dir = workspace_root().join(day.crate_name());
+    let dirs = [crate_dir.join("src"), crate_dir.join("resources")];
+    println!(
+        "Watching {} and {}",
+        relative(&dirs[0]).display(),
+        relative(&dirs[1]).display()
+    );
+
+    let mut previous: Option<Report> = None;
+    loop {
+        let snapshot = Snapshot::take(&dirs);
+        let report = run_once(day)?;
+        println!(
+            "Day {:>2}  {}",
+            day.number(),
+            report.summary(previous.as_ref())
+        );
+        previous = Some(report);
+
+        while Snapshot::take(&dirs) == snapshot {
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+fn relative(path: &Path) -> &Path {
+    path.strip_prefix(workspace_root()).unwrap_or(path)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, process};
+
+    use crate::watch::{parse_answers, Report, Snapshot};
+
+    #[test]
+    fn detects_changed_files() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", process::id()));
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/lib.rs"), "// one").unwrap();
+        let dirs = [dir.clone()];
+
+        let before = Snapshot::take(&dirs);
+        assert_eq!(Snapshot::take(&dirs), before);
+        fs::write(dir.join("src/lib.rs"), "// three").unwrap();
+        assert_ne!(Snapshot::take(&dirs), before);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn summarizes_changed_answers() {
+        let output = "{\"answer\":\"4360\",\"day\":9,\"part\":1}\n\
+                      {\"answer\":null,\"day\":9,\"part\":2}\n";
+        let previous = Report {
+            tests_passed: true,
+            answers: Ok(parse_answers(output)),
+        };
+        let current = Report {
+            tests_passed: false,
+            answers: Ok([Some("6354".to_owned()), None]),
+        };
+        assert_eq!(
+            current.summary(Some(&previous)),
+            "tests: FAIL  part 1: 4360 -> 6354  part 2: unsolved"
+        );
+        assert_eq!(
+            previous.to_string(),
+            "tests: pass  part 1: 4360  part 2: unsolved"
+        );
+    }
+}
@@ -0,0 +1,17 @@
+use aoc::{verify::verify_day, DAYS};
+
+#[test]
+fn real_inputs_match_accepted_answers() {
+    let failures: Vec<_> = DAYS
+        .iter()
+        .flat_map(verify_day)
+        .filter(|verification| verification.status.is_failure())
+        .map(|verification| {
+            format!(
+                "day {} part {}: {}",
+                verification.day, verification.part, verification.status
+            )
+        })
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -0,0 +1,252 @@
+use std::{fmt::Display, fs, io::ErrorKind, path::Path, time::Duration};
+
+use serde_json::{json, Map, Value};
+
+use crate::{Error, ParseError, Result};
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("Part {value} does not exist, expected 1 or 2.")),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", u8::from(*self))
+    }
+}
+
+/// Answer to a puzzle part, which may know more about itself than the value to submit.
+pub trait Answer: Display {
+    /// Additional facts reported next to the answer in JSON output.
+    fn details(&self) -> Map<String, Value> {
+        Map::new()
+    }
+
+    fn is_solved(&self) -> bool {
+        true
+    }
+}
+
+impl Answer for usize {}
+impl Answer for u32 {}
+impl Answer for String {}
+
+/// Placeholder answer for a part that has not been solved yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("unsolved")
+    }
+}
+
+impl Answer for Unsolved {
+    fn is_solved(&self) -> bool {
+        false
+    }
+}
+
+/// A computed [`Answer`] of a single part, rendered so all days can be handled uniformly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Output {
+    /// The value to submit, `None` if the part is not solved yet.
+    pub answer: Option<String>,
+    pub details: Map<String, Value>,
+    pub elapsed: Duration,
+}
+
+impl Output {
+    pub fn new(answer: &impl Answer, elapsed: Duration) -> Self {
+        Output {
+            answer: answer.is_solved().then(|| answer.to_string()),
+            details: answer.details(),
+            elapsed,
+        }
+    }
+}
+
+impl Display for Output {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.answer.as_deref().unwrap_or("unsolved"))
+    }
+}
+
+/// Answers of a single solver run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answers {
+    pub day: u8,
+    pub part1: Option<Output>,
+    pub part2: Option<Output>,
+    /// Time spent parsing the input.
+    pub parse: Duration,
+    /// Time spent parsing and solving all requested parts.
+    pub elapsed: Duration,
+}
+
+impl Answers {
+    pub fn output(&self, part: Part) -> Option<&Output> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    /// The value to submit for `part`, if it was requested and is solved.
+    pub fn get(&self, part: Part) -> Option<&str> {
+        self.output(part)
+            .and_then(|output| output.answer.as_deref())
+    }
+
+    /// One JSON object per computed part, holding the answer and its details.
+    pub fn to_json(&self) -> Vec<Value> {
+        [Part::One, Part::Two]
+            .into_iter()
+            .filter_map(|part| self.output(part).map(|output| (part, output)))
+            .map(|(part, output)| {
+                let mut object = json!({
+                    "day": self.day,
+                    "part": u8::from(part),
+                    "answer": output.answer,
+                    "elapsed_ms": output.elapsed.as_secs_f64() * 1000.0,
+                    "parse_ms": self.parse.as_secs_f64() * 1000.0,
+                });
+                if let Value::Object(object) = &mut object {
+                    object.extend(output.details.clone());
+                }
+                object
+            })
+            .collect()
+    }
+}
+
+/// Accepted answers of a day, as recorded in its `resources/answers` file.
+///
+/// The file holds one `partN: answer` line per solved part, parts without a line are
+/// reported as missing.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Expected {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+impl Expected {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut expected = Expected::default();
+        for (index, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (key, answer) = line.split_once(':').ok_or_else(|| {
+                ParseError::new(format!("Expected `partN: answer`, found {line}."))
+                    .at_line(index + 1)
+            })?;
+            let answer = Some(answer.trim().to_owned());
+            match key.trim() {
+                "part1" => expected.part1 = answer,
+                "part2" => expected.part2 = answer,
+                key => {
+                    return Err(ParseError::new(format!("Unknown part {key}.")).at_line(index + 1))
+                }
+            }
+        }
+        Ok(expected)
+    }
+
+    /// Loads the answers stored at `path`, a missing file simply has no answers yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(input) => Ok(Expected::parse(&input).map_err(|err| err.in_file(path))?),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Expected::default()),
+            Err(err) => Err(Error::io(path, err)),
+        }
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+/// How answers are written to stdout.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// Human readable lines.
+    #[default]
+    Text,
+    /// One JSON object per line and part.
+    Json,
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use serde_json::{json, Map};
+
+    use crate::answer::{Answers, Expected, Output, Part};
+
+    #[test]
+    fn renders_json_per_part() {
+        let mut details = Map::new();
+        details.insert("outer".to_owned(), json!(16));
+        let answers = Answers {
+            day: 8,
+            part1: Some(Output {
+                answer: Some("21".to_owned()),
+                details,
+                elapsed: Duration::from_millis(2),
+            }),
+            part2: Some(Output {
+                answer: None,
+                details: Map::new(),
+                elapsed: Duration::ZERO,
+            }),
+            parse: Duration::from_millis(1),
+            elapsed: Duration::from_millis(3),
+        };
+        assert_eq!(
+            answers.to_json(),
+            vec![
+                json!({"day": 8, "part": 1, "answer": "21", "elapsed_ms": 2.0, "parse_ms": 1.0, "outer": 16}),
+                json!({"day": 8, "part": 2, "answer": null, "elapsed_ms": 0.0, "parse_ms": 1.0}),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_expected_answers() {
+        let expected = Expected::parse("part1: 71124\n\npart2: GCFGLDNJZ\n").unwrap();
+        assert_eq!(expected.get(Part::One), Some("71124"));
+        assert_eq!(expected.get(Part::Two), Some("GCFGLDNJZ"));
+
+        let err = Expected::parse("part1: 1\npart3: 2\n").unwrap_err();
+        assert_eq!(err.to_string(), "input:2: Unknown part part3.");
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+};
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Everything that can go wrong while reading, parsing and solving a puzzle.
+///
+/// Panics are reserved for bugs in the solvers, so each variant maps onto its own
+/// [`exit code`](Error::exit_code) that scripts can tell apart from a crash (101).
+#[derive(Debug)]
+pub enum Error {
+    /// The binary was invoked with wrong or missing arguments.
+    Usage(String),
+    /// The puzzle input could not be read.
+    Io {
+        path: Option<PathBuf>,
+        source: io::Error,
+    },
+    /// The puzzle input does not follow the expected format.
+    Parse(ParseError),
+    /// The input is well-formed, but the puzzle has no answer for it.
+    Domain(String),
+    /// The puzzle website could not be reached or refused the request.
+    Remote(String),
+    /// A solver panicked, which was caught to keep other days running.
+    Panic(String),
+}
+
+impl Error {
+    pub fn io(path: impl Into<PathBuf>, source: io::Error) -> Self {
+        Error::Io {
+            path: Some(path.into()),
+            source,
+        }
+    }
+
+    pub fn domain(message: impl Into<String>) -> Self {
+        Error::Domain(message.into())
+    }
+
+    pub fn remote(message: impl Into<String>) -> Self {
+        Error::Remote(message.into())
+    }
+
+    /// Attaches the file the input was read from, if the error refers to the input.
+    pub fn in_file(self, file: impl AsRef<Path>) -> Self {
+        match self {
+            Error::Io { path: None, source } => Error::io(file.as_ref(), source),
+            Error::Parse(err) => Error::Parse(err.in_file(file)),
+            other => other,
+        }
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::Usage(_) => 2,
+            Error::Io { .. } => 3,
+            Error::Parse(_) => 4,
+            Error::Domain(_) => 5,
+            Error::Remote(_) => 6,
+            Error::Panic(_) => 101,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Usage(message) => f.write_str(message),
+            Error::Io {
+                path: Some(path),
+                source,
+            } => write!(f, "Unable to read input file {}: {source}", path.display()),
+            Error::Io { path: None, source } => write!(f, "Unable to read input: {source}"),
+            Error::Parse(err) => err.fmt(f),
+            Error::Domain(message) => f.write_str(message),
+            Error::Remote(message) => f.write_str(message),
+            Error::Panic(message) => write!(f, "Solver panicked: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(source: io::Error) -> Self {
+        Error::Io { path: None, source }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+/// Malformed puzzle input, located as precisely as the failing parser knows.
+///
+/// Parsers for a single token only know the column, the surrounding line loop adds the
+/// line number and the runner finally adds the file name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    file: Option<PathBuf>,
+    line: Option<usize>,
+    column: Option<usize>,
+    message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            file: None,
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    pub fn in_file(mut self, file: impl AsRef<Path>) -> Self {
+        self.file = Some(file.as_ref().to_owned());
+        self
+    }
+
+    pub fn file(&self) -> Option<&Path> {
+        self.file.as_deref()
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    pub fn message(&self) -> &str {
+        self.message.as_ref()
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}", file.display())?,
+            None => f.write_str("input")?,
+        }
+        if let Some(line) = self.line {
+            write!(f, ":{line}")?;
+            if let Some(column) = self.column {
+                write!(f, ":{column}")?;
+            }
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// 1-based column at which `part` starts within `line`.
+///
+/// `part` has to be a sub-slice of `line`, as returned by `split`, `trim` and friends.
+pub fn column(line: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+    line.get(..offset.min(line.len()))
+        .map(|prefix| prefix.chars().count())
+        .unwrap_or_default()
+        + 1
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::{column, Error, ParseError};
+
+    #[test]
+    fn locates_sub_slice() {
+        let line = "move 1 from 2 to 1";
+        let token = line.split(' ').nth(2).unwrap();
+        assert_eq!(column(line, token), 8);
+        assert_eq!(column(line, line), 1);
+    }
+
+    #[test]
+    fn formats_location() {
+        let err = ParseError::new("Unable to decode Q value.")
+            .at_column(3)
+            .at_line(12);
+        assert_eq!(err.to_string(), "input:12:3: Unable to decode Q value.");
+
+        let err = Error::from(err).in_file("resources/input");
+        assert_eq!(
+            err.to_string(),
+            "resources/input:12:3: Unable to decode Q value."
+        );
+        assert_eq!(err.exit_code(), 4);
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{solve, Answers, Error, Expected, Params, Part, Result, Solution};
+
+/// The worked example of a puzzle text and its answers, embedded into the day's crate with
+/// [`example!`](crate::example!).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Example {
+    pub input: &'static str,
+    /// Expected answers in the format of [`Expected::parse`].
+    pub answers: &'static str,
+}
+
+impl Example {
+    pub fn expected(&self) -> Result<Expected> {
+        Expected::parse(self.answers).map_err(|err| Error::from(err).in_file("example_answers"))
+    }
+}
+
+/// Embeds `resources/example` and `resources/example_answers` of the calling crate.
+#[macro_export]
+macro_rules! example {
+    () => {
+        $crate::Example {
+            input: include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/resources/example")),
+            answers: include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/resources/example_answers"
+            )),
+        }
+    };
+}
+
+/// Solves the example of `S` with the original parameters and checks it against the
+/// expected answers, a part without an expected answer is not checked.
+pub fn check_example<S: Solution>() -> Result<Answers> {
+    let answers = solve::<S>(&mut S::EXAMPLE.input.as_bytes(), None, &Params::default())?;
+    let expected = S::EXAMPLE.expected()?;
+    for part in [Part::One, Part::Two] {
+        let actual = answers.get(part).unwrap_or("unsolved");
+        match expected.get(part) {
+            Some(expected) if expected != actual => {
+                return Err(Error::domain(format!(
+                    "Part {part} of the example is {actual}, expected {expected}."
+                )))
+            }
+            _ => {}
+        }
+    }
+    Ok(answers)
+}
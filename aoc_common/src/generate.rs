@@ -0,0 +1,106 @@
+use std::ops::Range;
+
+use crate::{Part, Solution};
+
+/// Small deterministic random number generator (SplitMix64) for synthetic inputs.
+///
+/// The same seed always yields the same numbers on every platform, so a failing
+/// generated input can be reproduced from its seed alone.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Number within `range`, which must not be empty.
+    pub fn range(&mut self, range: Range<usize>) -> usize {
+        assert!(!range.is_empty(), "cannot pick from an empty range");
+        let span = (range.end - range.start) as u128;
+        range.start + ((u128::from(self.next_u64()) * span) >> 64) as usize
+    }
+
+    /// `true` with a probability of `numerator / denominator`.
+    pub fn chance(&mut self, numerator: usize, denominator: usize) -> bool {
+        self.range(0..denominator) < numerator
+    }
+
+    /// Random element of `items`, which must not be empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len())]
+    }
+
+    /// Shuffles `items` in place (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.range(0..index + 1));
+        }
+    }
+}
+
+/// A synthetic puzzle input and, where the generator knows them, its answers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Generated {
+    /// Expected answer of `part` with the default [`Config`](crate::Config), if known.
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+/// Writes valid inputs of any size for a [`Solution`], to stress-test and fuzz it.
+pub trait Generator: Solution {
+    /// Generates an input of about `size` records, e.g. lines or elves, drawing all
+    /// randomness from `rng`.
+    fn generate(rng: &mut Rng, size: usize) -> Generated;
+}
+
+/// Signature of [`generate`] once it is instantiated for a concrete [`Generator`].
+pub type GenerateFn = fn(u64, usize) -> Generated;
+
+/// Generates an input of about `size` records for `G` from `seed`.
+pub fn generate<G: Generator>(seed: u64, size: usize) -> Generated {
+    G::generate(&mut Rng::new(seed), size)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::generate::Rng;
+
+    #[test]
+    fn repeats_numbers_of_seed() {
+        let numbers: Vec<_> = (0..3).map(|_| Rng::new(7).next_u64()).collect();
+        assert_eq!(numbers[0], numbers[1]);
+        // reference value of SplitMix64, keeps inputs stable across releases
+        assert_eq!(Rng::new(0).next_u64(), 0xe220_a839_7b1d_cdaf);
+
+        let mut rng = Rng::new(42);
+        assert!((0..1000)
+            .map(|_| rng.range(3..9))
+            .all(|n| (3..9).contains(&n)));
+        let mut items: Vec<_> = (0..20).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,300 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign},
+};
+
+/// A point on an integer plane, `x` grows to the right and `y` downwards like the rows
+/// of a [`Grid`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// Offset between two [`Point`]s, e.g. a step in a [`Direction`].
+pub type Vector = Point;
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// Steps along the axes needed to get from this point to `other`.
+    pub fn manhattan(self, other: Point) -> i64 {
+        (other.x - self.x).abs() + (other.y - self.y).abs()
+    }
+
+    /// Steps needed to get from this point to `other` if diagonal steps are allowed.
+    pub fn chebyshev(self, other: Point) -> i64 {
+        (other.x - self.x).abs().max((other.y - self.y).abs())
+    }
+
+    /// A vector of at most one step along each axis, pointing the same way.
+    pub fn signum(self) -> Vector {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    /// The four points next to this one along the axes.
+    pub fn neighbours(self) -> impl Iterator<Item = Point> {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .map(move |direction| self + direction.vector())
+    }
+
+    /// The eight points around this one, including the diagonal ones.
+    pub fn neighbours_diagonal(self) -> impl Iterator<Item = Point> {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| self + direction.vector())
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Vector) -> Self::Output {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Vector) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, rhs: Point) -> Self::Output {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Vector) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Vector;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Vector;
+
+    fn neg(self) -> Self::Output {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+/// One of the eight directions on a [`Grid`], north points to the first row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The four directions along the axes, clockwise from north.
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// A single step in this direction.
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction::North => Point::new(0, -1),
+            Direction::NorthEast => Point::new(1, -1),
+            Direction::East => Point::new(1, 0),
+            Direction::SouthEast => Point::new(1, 1),
+            Direction::South => Point::new(0, 1),
+            Direction::SouthWest => Point::new(-1, 1),
+            Direction::West => Point::new(-1, 0),
+            Direction::NorthWest => Point::new(-1, -1),
+        }
+    }
+}
+
+/// A dense rectangular grid of cells, addressed by [`Point`]s with `x` as the column and
+/// `y` as the row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    /// An empty grid whose rows will hold `cols` cells each.
+    pub fn new(cols: usize) -> Self {
+        Grid {
+            cells: Vec::new(),
+            cols,
+        }
+    }
+
+    /// Appends a row, which must hold exactly [`Grid::cols`] cells.
+    pub fn push_row(&mut self, row: Vec<T>) {
+        assert_eq!(row.len(), self.cols, "rows of a grid have the same length");
+        self.cells.extend(row);
+    }
+
+    pub fn rows(&self) -> usize {
+        self.cells.len().checked_div(self.cols).unwrap_or_default()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.offset(point).is_some()
+    }
+
+    /// Whether `point` is in the first or last row or column.
+    pub fn is_on_edge(&self, point: Point) -> bool {
+        self.contains(point)
+            && (point.x == 0
+                || point.y == 0
+                || point.x as usize == self.cols - 1
+                || point.y as usize == self.rows() - 1)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|offset| &mut self.cells[offset])
+    }
+
+    /// All points of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (rows, cols) = (self.rows() as i64, self.cols as i64);
+        (0..rows).flat_map(move |y| (0..cols).map(move |x| Point::new(x, y)))
+    }
+
+    /// Cells met when walking from `from` in `direction` until leaving the grid, nearest
+    /// first and without the cell at `from` itself.
+    pub fn ray(&self, from: Point, direction: Direction) -> Ray<'_, T> {
+        Ray {
+            grid: self,
+            point: from,
+            step: direction.vector(),
+        }
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok().filter(|&x| x < self.cols)?;
+        let y = usize::try_from(point.y).ok().filter(|&y| y < self.rows())?;
+        Some(y * self.cols + x)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point} lies outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point} lies outside of the grid"))
+    }
+}
+
+/// Iterator over the cells in one direction, see [`Grid::ray`].
+#[derive(Debug, Clone)]
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    point: Point,
+    step: Vector,
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = (Point, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.point + self.step;
+        let cell = self.grid.get(point)?;
+        self.point = point;
+        Some((point, cell))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::geometry::{Direction, Grid, Point};
+
+    #[test]
+    fn measures_distances() {
+        let (a, b) = (Point::new(1, -2), Point::new(4, 2));
+        assert_eq!(b - a, Point::new(3, 4));
+        assert_eq!(a + (b - a), b);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!((a - b).signum(), Point::new(-1, -1));
+        assert_eq!(Point::ORIGIN.neighbours().count(), 4);
+        assert!(Point::ORIGIN
+            .neighbours_diagonal()
+            .all(|point| point.chebyshev(Point::ORIGIN) == 1));
+    }
+
+    #[test]
+    fn casts_rays_to_the_edge() {
+        let mut grid = Grid::new(3);
+        grid.push_row(vec![1, 2, 3]);
+        grid.push_row(vec![4, 5, 6]);
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+
+        let cells = |from, direction| -> Vec<i32> {
+            grid.ray(from, direction).map(|(_, cell)| *cell).collect()
+        };
+        assert_eq!(cells(Point::new(0, 0), Direction::East), [2, 3]);
+        assert_eq!(cells(Point::new(2, 1), Direction::West), [5, 4]);
+        assert_eq!(cells(Point::new(0, 1), Direction::NorthEast), [2]);
+        assert!(cells(Point::new(1, 0), Direction::North).is_empty());
+        assert!(grid.is_on_edge(Point::new(1, 1)));
+        assert_eq!(grid.points().count(), 6);
+    }
+}
@@ -0,0 +1,355 @@
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{self, BufRead, BufReader, ErrorKind},
+    mem,
+    path::{Path, PathBuf},
+};
+
+use crate::{Error, Result};
+
+/// Where the puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// Name used to locate errors, the path for files and `<stdin>` otherwise.
+    pub fn name(&self) -> &Path {
+        match self {
+            Source::Stdin => Path::new("<stdin>"),
+            Source::File(path) => path,
+        }
+    }
+
+    /// Opens the input for reading it piece by piece instead of all at once.
+    pub fn open(&self) -> Result<Box<dyn BufRead>> {
+        match self {
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::File(path) => {
+                let file = File::open(path).map_err(|err| Error::io(path, err))?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+        }
+    }
+}
+
+impl From<PathBuf> for Source {
+    fn from(path: PathBuf) -> Self {
+        if path.as_os_str() == "-" {
+            Source::Stdin
+        } else {
+            Source::File(path)
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name().display())
+    }
+}
+
+/// Byte order mark some editors put at the start of a file.
+const BOM: char = '\u{feff}';
+
+/// Removes what differs between editors and platforms but never matters to a puzzle.
+///
+/// That is a leading byte order mark, `\r\n` line endings, whitespace at the end of each
+/// line and blank lines at the end of the input, including the final line ending. Leading
+/// whitespace and blank lines between other lines are kept. [`lines`] and [`records`]
+/// apply the same rules to each line while streaming, [`chars`] only skips the byte order
+/// mark.
+pub fn normalize(input: &str) -> String {
+    let lines: Vec<String> = input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let mut line = line.to_owned();
+            normalize_line(&mut line, index == 0);
+            line
+        })
+        .collect();
+    let end = lines
+        .iter()
+        .rposition(|line| !line.is_empty())
+        .map_or(0, |last| last + 1);
+    lines[..end].join("\n")
+}
+
+/// Applies the rules of [`normalize`] to a single line, read with or without its line
+/// ending. A byte order mark is only dropped from the `first` line of the input.
+fn normalize_line(line: &mut String, first: bool) {
+    line.truncate(line.trim_end().len());
+    if first && line.starts_with(BOM) {
+        line.drain(..BOM.len_utf8());
+    }
+}
+
+/// A line of the input without its line ending and trailing whitespace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    /// 1-based number of the line within the input.
+    pub number: usize,
+    pub text: String,
+}
+
+/// Iterator over the lines of a reader, see [`lines`].
+#[derive(Debug)]
+pub struct Lines<R> {
+    reader: R,
+    number: usize,
+    finished: bool,
+}
+
+/// Reads `reader` line by line, so only the current line is kept in memory.
+///
+/// Lines end at `\n` or `\r\n` and are [normalized](normalize) on the fly, a read error
+/// ends the iteration after being returned.
+pub fn lines<R: BufRead>(reader: R) -> Lines<R> {
+    Lines {
+        reader,
+        number: 0,
+        finished: false,
+    }
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = Result<Line>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let mut text = String::new();
+        match self.reader.read_line(&mut text) {
+            Ok(0) => {
+                self.finished = true;
+                None
+            }
+            Ok(_) => {
+                normalize_line(&mut text, self.number == 0);
+                self.number += 1;
+                Some(Ok(Line {
+                    number: self.number,
+                    text,
+                }))
+            }
+            Err(err) => {
+                self.finished = true;
+                Some(Err(Error::from(err)))
+            }
+        }
+    }
+}
+
+/// Iterator over groups of lines separated by blank lines, see [`records`].
+#[derive(Debug)]
+pub struct Records<R> {
+    lines: Lines<R>,
+}
+
+/// Reads `reader` as records of consecutive non-blank lines.
+///
+/// Any number of blank lines separates two records, no record is empty.
+pub fn records<R: BufRead>(reader: R) -> Records<R> {
+    Records {
+        lines: lines(reader),
+    }
+}
+
+impl<R: BufRead> Iterator for Records<R> {
+    type Item = Result<Vec<Line>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut record = Vec::new();
+        for line in self.lines.by_ref() {
+            match line {
+                Ok(line) if line.text.trim().is_empty() => {
+                    if !record.is_empty() {
+                        return Some(Ok(record));
+                    }
+                }
+                Ok(line) => record.push(line),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+        (!record.is_empty()).then_some(Ok(record))
+    }
+}
+
+/// Iterator over the characters of a reader, see [`chars`].
+#[derive(Debug)]
+pub struct Chars<R> {
+    reader: R,
+    /// Decoded characters not handed out yet, in reverse order.
+    decoded: Vec<char>,
+    /// Start of a character split between two reads of the underlying buffer.
+    partial: Vec<u8>,
+    /// Error to return once the characters decoded before it are handed out.
+    error: Option<Error>,
+    started: bool,
+    finished: bool,
+}
+
+/// Reads `reader` as a stream of UTF-8 characters, without ever splitting it into lines.
+///
+/// A leading byte order mark is skipped, invalid UTF-8 ends the iteration with an error
+/// after all characters before it.
+pub fn chars<R: BufRead>(reader: R) -> Chars<R> {
+    Chars {
+        reader,
+        decoded: Vec::new(),
+        partial: Vec::new(),
+        error: None,
+        started: false,
+        finished: false,
+    }
+}
+
+impl<R: BufRead> Chars<R> {
+    /// Decodes the next chunk of the reader.
+    fn fill(&mut self) {
+        let chunk = match self.reader.fill_buf() {
+            Ok(chunk) => chunk,
+            Err(err) => {
+                self.error = Some(Error::from(err));
+                return;
+            }
+        };
+        if chunk.is_empty() {
+            if !self.partial.is_empty() {
+                self.error = Some(invalid_utf8());
+            }
+            self.finished = true;
+            return;
+        }
+        let length = chunk.len();
+        let mut bytes = mem::take(&mut self.partial);
+        bytes.extend_from_slice(chunk);
+        self.reader.consume(length);
+
+        let valid = match std::str::from_utf8(&bytes) {
+            Ok(text) => text.len(),
+            Err(err) => {
+                if err.error_len().is_some() {
+                    self.error = Some(invalid_utf8());
+                }
+                err.valid_up_to()
+            }
+        };
+        self.partial = bytes.split_off(valid);
+        let text = std::str::from_utf8(&bytes).expect("prefix is valid UTF-8");
+        self.decoded.extend(text.chars().rev());
+    }
+}
+
+fn invalid_utf8() -> Error {
+    Error::from(io::Error::new(
+        ErrorKind::InvalidData,
+        "stream did not contain valid UTF-8",
+    ))
+}
+
+impl<R: BufRead> Iterator for Chars<R> {
+    type Item = Result<char>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.decoded.is_empty() {
+            if let Some(err) = self.error.take() {
+                self.finished = true;
+                return Some(Err(err));
+            }
+            if self.finished {
+                return None;
+            }
+            self.fill();
+            if !self.started && self.decoded.last() == Some(&BOM) {
+                self.decoded.pop();
+            }
+            self.started |= !self.decoded.is_empty();
+        }
+        self.decoded.pop().map(Ok)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{io::BufReader, path::PathBuf};
+
+    use crate::input::{chars, lines, normalize, records, Line, Source};
+
+    #[test]
+    fn reads_stdin_for_dash() {
+        assert_eq!(Source::from(PathBuf::from("-")), Source::Stdin);
+        assert_eq!(
+            Source::from(PathBuf::from("resources/input")),
+            Source::File(PathBuf::from("resources/input"))
+        );
+    }
+
+    #[test]
+    fn reports_missing_file() {
+        let source = Source::from(PathBuf::from("does/not/exist"));
+        let err = source.open().err().unwrap();
+        assert_eq!(err.exit_code(), 3);
+        assert!(err
+            .to_string()
+            .starts_with("Unable to read input file does/not/exist:"));
+    }
+
+    #[test]
+    fn normalizes_line_endings() {
+        let input = "\u{feff}  [D] \r\n1 2\t\r\n\r\n3\r\n \r\n\r\n";
+        assert_eq!(normalize(input), "  [D]\n1 2\n\n3");
+        assert_eq!(normalize("\n\n"), "");
+
+        let texts: Vec<_> = lines(input.as_bytes())
+            .map(|line| line.unwrap().text)
+            .collect();
+        assert_eq!(texts, ["  [D]", "1 2", "", "3", "", ""]);
+        assert_eq!(texts[..4].join("\n"), normalize(input));
+        let decoded: String = chars("\u{feff}ab\u{feff}".as_bytes())
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(decoded, "ab\u{feff}");
+    }
+
+    #[test]
+    fn reads_lines_and_records() {
+        let input = "1000\r\n2000\n\n\n3000\n";
+        let texts: Vec<_> = lines(input.as_bytes())
+            .map(|line| line.unwrap().text)
+            .collect();
+        assert_eq!(texts, ["1000", "2000", "", "", "3000"]);
+
+        let mut lines = lines(&b"a\n\xff\nb\n"[..]);
+        assert_eq!(lines.next().unwrap().unwrap().text, "a");
+        assert_eq!(lines.next().unwrap().unwrap_err().exit_code(), 3);
+        assert!(lines.next().is_none());
+
+        let records: Vec<_> = records(input.as_bytes()).map(Result::unwrap).collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(
+            records[1],
+            [Line {
+                number: 5,
+                text: "3000".to_owned()
+            }]
+        );
+    }
+
+    #[test]
+    fn decodes_chars_across_reads() {
+        // a capacity of 1 splits every multi-byte character between two reads
+        let reader = BufReader::with_capacity(1, "añ€😀".as_bytes());
+        let decoded: String = chars(reader).map(Result::unwrap).collect();
+        assert_eq!(decoded, "añ€😀");
+
+        let err = chars(&[b'a', 0xff][..]).nth(1).unwrap().unwrap_err();
+        assert_eq!(err.exit_code(), 3);
+        assert!(chars(&[b'a', 0xe2, 0x82][..]).any(|c| c.is_err()));
+    }
+}
@@ -0,0 +1,324 @@
+//! Shared infrastructure of the daily solutions: the [`Solution`] trait, error types,
+//! input handling and the command line runner each `exc_NN` binary delegates to.
+
+use std::{
+    io::{self, BufRead, IsTerminal},
+    path::PathBuf,
+    process,
+    time::{Duration, Instant},
+};
+
+use clap::{ArgAction, Args, Parser};
+use serde_json::json;
+use tracing::{debug, info_span};
+use tracing_subscriber::EnvFilter;
+
+mod answer;
+mod error;
+mod example;
+mod generate;
+mod geometry;
+mod input;
+mod params;
+pub mod parse;
+
+pub use answer::{Answer, Answers, Expected, Format, Output, Part, Unsolved};
+pub use error::{column, Error, ParseError, Result};
+pub use example::{check_example, Example};
+pub use generate::{generate, GenerateFn, Generated, Generator, Rng};
+pub use geometry::{Direction, Grid, Point, Ray, Vector};
+pub use input::{chars, lines, normalize, records, Chars, Line, Lines, Records, Source};
+pub use nom;
+pub use params::{Config, Params};
+pub use serde_json::{self, Map, Value};
+pub use tracing;
+
+/// A single day of the advent calendar, split into parsing and the two puzzle parts.
+///
+/// Each `exc_NN` crate implements this trait on a unit struct and hands it to [`run`],
+/// which takes care of reading the input file and printing the answers.
+pub trait Solution {
+    /// Day of the advent calendar this solution belongs to.
+    const DAY: u8;
+    /// Worked example of the puzzle text, usually [`example!`].
+    const EXAMPLE: Example;
+
+    /// Parsed puzzle input shared by both parts.
+    type Input;
+    /// Parameters of the puzzle, `()` if there are none.
+    type Config: Config;
+    /// Answer of the first part.
+    type Part1: Answer;
+    /// Answer of the second part.
+    type Part2: Answer;
+
+    /// Parses the raw text of the input, which is expected to [`normalize`] it first.
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Parses the input straight from `reader`.
+    ///
+    /// By default the whole input is read into memory and handed to [`Solution::parse`].
+    /// Days whose parsed input is much smaller than its text override this with the
+    /// [`lines`], [`records`] or [`chars`] of the reader.
+    fn read<R: BufRead>(mut reader: R) -> Result<Self::Input> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Self::parse(&input)
+    }
+
+    fn part1(input: &Self::Input, config: &Self::Config) -> Result<Self::Part1>;
+    fn part2(input: &Self::Input, config: &Self::Config) -> Result<Self::Part2>;
+}
+
+/// Signature of [`solve`] once it is instantiated for a concrete [`Solution`].
+pub type Solver = fn(&mut dyn BufRead, Option<Part>, &Params) -> Result<Answers>;
+
+/// Reads and parses `input` and solves the requested `part` of `S`, or both parts if none
+/// is given. The parse time includes reading the input.
+///
+/// Parameters not set in `params` keep the values of the original puzzle.
+pub fn solve<S: Solution>(
+    input: &mut dyn BufRead,
+    part: Option<Part>,
+    params: &Params,
+) -> Result<Answers> {
+    params.check(S::Config::KEYS)?;
+    let config = S::Config::from_params(params)?;
+
+    let _solve = info_span!("solve", day = S::DAY).entered();
+    let start = Instant::now();
+    let input = info_span!("parse").in_scope(|| S::read(input))?;
+    let parse = start.elapsed();
+    debug!(elapsed = %format_duration(parse), "parsed input");
+    let part1 = match part {
+        None | Some(Part::One) => {
+            let _span = info_span!("part1").entered();
+            let start = Instant::now();
+            let answer = S::part1(&input, &config)?;
+            let elapsed = start.elapsed();
+            debug!(%answer, elapsed = %format_duration(elapsed), "solved part 1");
+            Some(Output::new(&answer, elapsed))
+        }
+        _ => None,
+    };
+    let part2 = match part {
+        None | Some(Part::Two) => {
+            let _span = info_span!("part2").entered();
+            let start = Instant::now();
+            let answer = S::part2(&input, &config)?;
+            let elapsed = start.elapsed();
+            debug!(%answer, elapsed = %format_duration(elapsed), "solved part 2");
+            Some(Output::new(&answer, elapsed))
+        }
+        _ => None,
+    };
+
+    Ok(Answers {
+        day: S::DAY,
+        part1,
+        part2,
+        parse,
+        elapsed: start.elapsed(),
+    })
+}
+
+/// Signature of [`describe_config`] once it is instantiated for a concrete [`Solution`].
+pub type DescribeFn = fn(&Params) -> Result<String>;
+
+/// The configuration of `S` that `params` resolve to, rendered with [`Debug`].
+///
+/// Parameters set to the values of the original puzzle describe the same configuration as
+/// no parameters at all.
+pub fn describe_config<S: Solution>(params: &Params) -> Result<String> {
+    params.check(S::Config::KEYS)?;
+    Ok(format!("{:?}", S::Config::from_params(params)?))
+}
+
+/// Writes `answers` to stdout in the requested `format`.
+pub fn print_answers(answers: &Answers, format: Format) {
+    match format {
+        Format::Text => {
+            for part in [Part::One, Part::Two] {
+                if let Some(output) = answers.output(part) {
+                    println!("Part {part}: {output}");
+                }
+            }
+        }
+        Format::Json => {
+            for object in answers.to_json() {
+                println!("{object}");
+            }
+        }
+    }
+}
+
+/// Writes the answers to the example next to the expected ones and returns whether they
+/// all match, parts without an expected answer are not checked.
+pub fn print_example(answers: &Answers, expected: &Expected, format: Format) -> bool {
+    let mut matches = true;
+    for part in [Part::One, Part::Two] {
+        let Some(output) = answers.output(part) else {
+            continue;
+        };
+        let expected = expected.get(part);
+        let actual = output.to_string();
+        let passed = expected.is_none_or(|expected| expected == actual);
+        matches &= passed;
+        match format {
+            Format::Text => {
+                let verdict = match expected {
+                    Some(expected) if passed => format!("(expected {expected})"),
+                    Some(expected) => format!("(expected {expected}, MISMATCH)"),
+                    None => "(no expected answer)".to_owned(),
+                };
+                println!("Part {part}: {output} {verdict}");
+            }
+            Format::Json => println!(
+                "{}",
+                json!({
+                    "day": answers.day,
+                    "part": u8::from(part),
+                    "answer": output.answer,
+                    "expected": expected,
+                    "matches": passed,
+                })
+            ),
+        }
+    }
+    matches
+}
+
+/// Writes how long parsing and each computed part took to stdout.
+pub fn print_timings(answers: &Answers) {
+    println!("Parse:  {:>12}", format_duration(answers.parse));
+    for part in [Part::One, Part::Two] {
+        if let Some(output) = answers.output(part) {
+            println!("Part {part}: {:>12}", format_duration(output.elapsed));
+        }
+    }
+    println!("Total:  {:>12}", format_duration(answers.elapsed));
+}
+
+/// Renders `duration` in milliseconds, precise enough to compare fast days.
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
+
+#[derive(Parser)]
+struct RunArgs {
+    /// Puzzle input, read from stdin if missing or `-`.
+    input: Option<PathBuf>,
+    /// Solve the worked example of the puzzle text and compare it with its expected answers.
+    ///
+    /// The expected answers belong to the original puzzle parameters, so none can be set.
+    #[arg(long, conflicts_with_all = ["input", "set", "config"])]
+    example: bool,
+    /// Output format of the answers.
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+    /// Report how long parsing and each part took.
+    #[arg(long)]
+    time: bool,
+    #[command(flatten)]
+    params: ParamArgs,
+    #[command(flatten)]
+    log: LogArgs,
+}
+
+/// Command line option turning on log output.
+#[derive(Debug, Default, Args)]
+pub struct LogArgs {
+    /// Logs the steps of the solver to stderr, repeat for every single step. Without it
+    /// the `RUST_LOG` environment variable selects what is logged, if set.
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
+}
+
+impl LogArgs {
+    /// Filter selecting what is logged, `None` if logging stays off.
+    pub fn filter(&self) -> Option<EnvFilter> {
+        match self.verbose {
+            0 => EnvFilter::try_from_default_env().ok(),
+            1 => Some(EnvFilter::new("debug")),
+            _ => Some(EnvFilter::new("trace")),
+        }
+    }
+
+    /// Installs the logger writing to stderr, so the answers on stdout stay clean.
+    pub fn init(&self) {
+        if let Some(filter) = self.filter() {
+            tracing_subscriber::fmt()
+                .with_env_filter(filter)
+                .with_writer(io::stderr)
+                .with_ansi(io::stderr().is_terminal())
+                .init();
+        }
+    }
+}
+
+/// Command line options setting puzzle parameters.
+#[derive(Debug, Default, Args)]
+pub struct ParamArgs {
+    /// Config file with one `key = value` puzzle parameter per line.
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+    /// Sets a puzzle parameter, overriding the config file.
+    #[arg(long = "set", value_name = "KEY=VALUE")]
+    pub set: Vec<String>,
+}
+
+impl ParamArgs {
+    /// Adds the parameters of the config file and then those set one by one to `params`.
+    pub fn apply(&self, mut params: Params) -> Result<Params> {
+        if let Some(path) = &self.config {
+            if !path.is_file() {
+                return Err(Error::Usage(format!(
+                    "Config file {} does not exist.",
+                    path.display()
+                )));
+            }
+            params.extend(Params::load(path)?);
+        }
+        for assignment in &self.set {
+            params.set(assignment).map_err(Error::Usage)?;
+        }
+        Ok(params)
+    }
+}
+
+/// Reads the input file given on the command line and prints both answers of `S`.
+///
+/// Without a path, or if it is `-`, the input is read from stdin instead.
+/// Errors are reported on stderr and terminate the process with [`Error::exit_code`].
+pub fn run<S: Solution>() {
+    let args = RunArgs::parse();
+    args.log.init();
+    if let Err(err) = try_run::<S>(args) {
+        eprintln!("Error: {err}");
+        process::exit(err.exit_code());
+    }
+}
+
+fn try_run<S: Solution>(args: RunArgs) -> Result<()> {
+    if args.example {
+        let answers = solve::<S>(&mut S::EXAMPLE.input.as_bytes(), None, &Params::default())?;
+        if !print_example(&answers, &S::EXAMPLE.expected()?, args.format) {
+            return Err(Error::domain(
+                "Answers to the example don't match the expected ones",
+            ));
+        }
+        return Ok(());
+    }
+
+    let params = args.params.apply(Params::default())?;
+    let source = args.input.map(Source::from).unwrap_or(Source::Stdin);
+
+    let answers =
+        solve::<S>(&mut source.open()?, None, &params).map_err(|err| err.in_file(source.name()))?;
+    print_answers(&answers, args.format);
+    // JSON output carries the timings already
+    if args.time && args.format == Format::Text {
+        print_timings(&answers);
+    }
+    Ok(())
+}
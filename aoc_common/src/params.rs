@@ -0,0 +1,151 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Display},
+    fs,
+    io::ErrorKind,
+    path::Path,
+    str::FromStr,
+};
+
+use crate::{Error, ParseError, Result};
+
+/// Puzzle parameters given as `key = value` pairs, from a config file or the command line.
+///
+/// Values stay strings until a day's [`Config`] asks for them, so each day decides which
+/// keys it knows and how their values are parsed.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    /// Parses a config file with one `key = value` pair per line, `#` starts a comment.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut params = Params::default();
+        for (index, line) in input.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            params
+                .set(line)
+                .map_err(|message| ParseError::new(message).at_line(index + 1))?;
+        }
+        Ok(params)
+    }
+
+    /// Loads the config file at `path`, a missing file holds no parameters.
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(input) => Ok(Params::parse(&input).map_err(|err| err.in_file(path))?),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Params::default()),
+            Err(err) => Err(Error::io(path, err)),
+        }
+    }
+
+    /// Sets a parameter from a `key=value` assignment.
+    pub fn set(&mut self, assignment: &str) -> Result<(), String> {
+        let (key, value) = assignment
+            .split_once('=')
+            .ok_or_else(|| format!("Expected `key=value`, found {assignment}."))?;
+        self.0
+            .insert(key.trim().to_owned(), value.trim().to_owned());
+        Ok(())
+    }
+
+    /// Adds all parameters of `other`, replacing those that are set already.
+    pub fn extend(&mut self, other: Params) {
+        self.0.extend(other.0);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Value of `key` parsed as `T`, or `default` if it is not set.
+    pub fn get<T>(&self, key: &str, default: T) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        match self.0.get(key) {
+            Some(value) => value
+                .parse()
+                .map_err(|err| Error::Usage(format!("Invalid value {value} for {key}: {err}"))),
+            None => Ok(default),
+        }
+    }
+
+    /// Raw value of `key`, if it is set.
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    /// Fails for the first parameter whose key is not one of `known`.
+    pub fn check(&self, known: &[&str]) -> Result<()> {
+        match self.0.keys().find(|key| !known.contains(&key.as_str())) {
+            Some(key) if known.is_empty() => Err(Error::Usage(format!(
+                "Unknown parameter {key}, this day has no parameters."
+            ))),
+            Some(key) => Err(Error::Usage(format!(
+                "Unknown parameter {key}, expected one of {}.",
+                known.join(", ")
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Typed parameters of a [`Solution`](crate::Solution), [`Default`] holds the values of
+/// the original puzzle and [`Debug`] renders the resolved values.
+pub trait Config: Default + Debug {
+    /// Keys understood by [`Config::from_params`].
+    const KEYS: &'static [&'static str];
+
+    /// Reads the configuration from `params`, falling back to the defaults for unset keys.
+    fn from_params(params: &Params) -> Result<Self>;
+}
+
+impl Config for () {
+    const KEYS: &'static [&'static str] = &[];
+
+    fn from_params(_params: &Params) -> Result<Self> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::params::Params;
+
+    #[test]
+    fn reads_config_file() {
+        let mut params = Params::parse("# disk\ndisk_size = 100\n\nthreshold=5 # small\n").unwrap();
+        assert_eq!(params.get("disk_size", 0_usize).unwrap(), 100);
+        assert_eq!(params.get("threshold", 0_usize).unwrap(), 5);
+        assert_eq!(params.get("missing", 7_usize).unwrap(), 7);
+
+        params.set("threshold=x").unwrap();
+        let err = params.get("threshold", 0_usize).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid value x for threshold: invalid digit found in string"
+        );
+
+        let err = Params::parse("top 3\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "input:1: Expected `key=value`, found top 3."
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        let params = Params::parse("top = 5\n").unwrap();
+        assert!(params.check(&["top"]).is_ok());
+        let err = params.check(&["group_size"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown parameter top, expected one of group_size."
+        );
+        assert_eq!(err.exit_code(), 2);
+    }
+}
@@ -0,0 +1,235 @@
+//! Parser combinators shared by the days, built on [`nom`].
+//!
+//! Each day writes the grammar of its input with `nom` and the primitives here, and runs it
+//! with [`parse_all`] or [`Line::parse`], which turn a [`Mismatch`] into a [`ParseError`]
+//! located by line and column.
+
+use std::{fmt::Display, ops::RangeInclusive, str::FromStr};
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, digit1, space0, space1},
+    combinator::{all_consuming, eof, opt, recognize},
+    error::{ContextError, ErrorKind, FromExternalError},
+    multi::separated_list1,
+    sequence::{pair, preceded, separated_pair, terminated},
+    Finish, Parser,
+};
+
+use crate::{Line, ParseError};
+
+/// Result of the parsers working with [`Mismatch`].
+pub type IResult<'a, T> = nom::IResult<&'a str, T, Mismatch<'a>>;
+
+/// Why a parser failed and the remaining input at the point it failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch<'a> {
+    pub input: &'a str,
+    pub message: String,
+}
+
+impl<'a> Mismatch<'a> {
+    pub fn new(input: &'a str, message: impl Into<String>) -> Self {
+        Mismatch {
+            input,
+            message: message.into(),
+        }
+    }
+
+    /// Locates the mismatch within `input`, the text the failed parser was started on.
+    pub fn locate(&self, input: &str) -> ParseError {
+        let offset = input.len().saturating_sub(self.input.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        ParseError::new(self.message.clone())
+            .at_line(before.matches('\n').count() + 1)
+            .at_column(before[line_start..].chars().count() + 1)
+    }
+}
+
+impl<'a> nom::error::ParseError<&'a str> for Mismatch<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        let message = match kind {
+            _ if input.is_empty() => "Unexpected end of line.".to_owned(),
+            ErrorKind::Eof => format!("Expected end of line, found {}.", input.trim()),
+            ErrorKind::Digit => "Expected a number.".to_owned(),
+            _ => format!("Unexpected {}.", input.trim()),
+        };
+        Mismatch::new(input, message)
+    }
+
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    /// Of two failed alternatives the one that got further is the more precise.
+    fn or(self, other: Self) -> Self {
+        if self.input.len() < other.input.len() {
+            self
+        } else {
+            other
+        }
+    }
+}
+
+/// Names what was expected where a parser wrapped in [`nom::error::context`] failed, an
+/// error found further into the input is more precise and kept as it is.
+impl<'a> ContextError<&'a str> for Mismatch<'a> {
+    fn add_context(input: &'a str, context: &'static str, other: Self) -> Self {
+        if other.input.len() < input.len() {
+            other
+        } else {
+            Mismatch::new(input, format!("Expected {context}."))
+        }
+    }
+}
+
+impl<'a, E: Display> FromExternalError<&'a str, E> for Mismatch<'a> {
+    fn from_external_error(input: &'a str, _kind: ErrorKind, err: E) -> Self {
+        Mismatch::new(input, err.to_string())
+    }
+}
+
+/// Runs `parser` on all of `input`, failing if anything is left over.
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    parser: impl Parser<&'a str, T, Mismatch<'a>>,
+) -> Result<T, ParseError> {
+    all_consuming(parser)(input)
+        .finish()
+        .map(|(_, value)| value)
+        .map_err(|mismatch| mismatch.locate(input))
+}
+
+impl Line {
+    /// Runs `parser` on the text of this line after its indentation.
+    pub fn parse<'a, T>(
+        &'a self,
+        parser: impl Parser<&'a str, T, Mismatch<'a>>,
+    ) -> Result<T, ParseError> {
+        parse_all(&self.text, preceded(space0, parser)).map_err(|err| err.at_line(self.number))
+    }
+}
+
+/// Decimal number without a sign, like `42`.
+pub fn unsigned<'a, T>(input: &'a str) -> IResult<'a, T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let (rest, digits) = digit1(input)?;
+    let value = digits.parse().map_err(|err| {
+        nom::Err::Error(Mismatch::new(
+            input,
+            format!("Invalid number {digits}: {err}"),
+        ))
+    })?;
+    Ok((rest, value))
+}
+
+/// Decimal number with an optional minus sign, like `-7`.
+pub fn signed<'a, T>(input: &'a str) -> IResult<'a, T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let (rest, number) = recognize(pair(opt(char('-')), digit1))(input)?;
+    let value = number.parse().map_err(|err| {
+        nom::Err::Error(Mismatch::new(
+            input,
+            format!("Invalid number {number}: {err}"),
+        ))
+    })?;
+    Ok((rest, value))
+}
+
+/// Inclusive range of numbers written as `2-4`.
+pub fn range<'a, T>(input: &'a str) -> IResult<'a, RangeInclusive<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    separated_pair(unsigned, char('-'), unsigned)
+        .map(|(start, end)| start..=end)
+        .parse(input)
+}
+
+/// The word `word` followed by spaces or the end of the line, e.g. the `move` of
+/// `move 3 from 1 to 2`.
+pub fn keyword<'a>(word: &'static str) -> impl FnMut(&'a str) -> IResult<'a, &'a str> {
+    move |input| {
+        let result: IResult<'a, &'a str> = terminated(tag(word), alt((space1, eof)))(input);
+        result.map_err(|_| nom::Err::Error(Mismatch::new(input, format!("Expected {word}."))))
+    }
+}
+
+/// Items separated by `separator` and optional spaces around it, like `1, 2, 3`.
+pub fn record<'a, T>(
+    separator: char,
+    item: impl Parser<&'a str, T, Mismatch<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    separated_list1(terminated(preceded(space0, char(separator)), space0), item)
+}
+
+#[cfg(test)]
+mod test {
+    use nom::{
+        character::complete::newline,
+        error::context,
+        sequence::{pair, preceded},
+        Parser,
+    };
+
+    use crate::{
+        parse::{keyword, parse_all, range, record, signed, unsigned},
+        Line,
+    };
+
+    #[test]
+    fn parses_primitives() {
+        assert_eq!(parse_all("42", unsigned::<u8>).unwrap(), 42);
+        assert_eq!(parse_all("-7", signed::<i32>).unwrap(), -7);
+        assert_eq!(parse_all("2-4", range::<u8>).unwrap(), 2..=4);
+        assert_eq!(
+            parse_all("move 3", preceded(keyword("move"), unsigned::<u8>)).unwrap(),
+            3
+        );
+        assert_eq!(
+            parse_all("1, 2,3", record(',', unsigned::<u8>)).unwrap(),
+            [1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn locates_mismatches() {
+        let err = parse_all("300", unsigned::<u8>).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "input:1:1: Invalid number 300: number too large to fit in target type"
+        );
+        let err = parse_all(
+            "1\nx",
+            preceded(pair(unsigned::<u8>, newline), unsigned::<u8>),
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), "input:2:1: Expected a number.");
+
+        let line = Line {
+            number: 7,
+            text: "  move x".to_owned(),
+        };
+        let count = context("a crate count", unsigned::<u8>);
+        let err = line.parse(preceded(keyword("move"), count)).unwrap_err();
+        assert_eq!(err.to_string(), "input:7:8: Expected a crate count.");
+        let err = line.parse(keyword("take")).unwrap_err();
+        assert_eq!(err.to_string(), "input:7:3: Expected take.");
+        let err = Line {
+            number: 1,
+            text: "1 2".to_owned(),
+        }
+        .parse(record(',', unsigned::<u8>).map(|numbers| numbers.len()))
+        .unwrap_err();
+        assert_eq!(err.to_string(), "input:1:2: Expected end of line, found 2.");
+    }
+}
@@ -0,0 +1,154 @@
+//! Day 1: Calorie Counting.
+//!
+//! The input lists the calories of each item an elf carries, with a blank line between
+//! elves. [`Day01`] parses it into the per-elf totals, sorted from largest to smallest.
+
+use std::io::BufRead;
+
+use aoc_common::{
+    example,
+    nom::error::context,
+    parse::unsigned,
+    records,
+    tracing::{debug, trace},
+    Error, Example, Generated, Generator, Line, Params, ParseError, Result, Rng, Solution,
+};
+
+/// Parameters of the puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// Number of elves carrying the most calories summed up in part 2.
+    pub top: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { top: 3 }
+    }
+}
+
+impl aoc_common::Config for Config {
+    const KEYS: &'static [&'static str] = &["top"];
+
+    fn from_params(params: &Params) -> Result<Self> {
+        let default = Config::default();
+        Ok(Config {
+            top: params.get("top", default.top)?,
+        })
+    }
+}
+
+/// Solution of day 1, counting the calories carried by each elf.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const EXAMPLE: Example = example!();
+
+    type Input = Vec<usize>;
+    type Config = Config;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::read(input.as_bytes())
+    }
+
+    fn read<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let mut elves = records(reader)
+            .map(|elf| {
+                let elf = elf?;
+                let calories = calculate_calories(&elf)?;
+                trace!(line = elf[0].number, calories, "counted elf");
+                Ok(calories)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        debug!(elves = elves.len(), "parsed elves");
+        elves.sort();
+        elves.reverse();
+        Ok(elves)
+    }
+
+    fn part1(elves: &Self::Input, _config: &Self::Config) -> Result<Self::Part1> {
+        elves
+            .first()
+            .copied()
+            .ok_or_else(|| Error::domain("Input doesn't list any elves"))
+    }
+
+    fn part2(elves: &Self::Input, config: &Self::Config) -> Result<Self::Part2> {
+        Ok(elves.iter().take(config.top).sum())
+    }
+}
+
+/// Sums up the calories of a single elf.
+fn calculate_calories(elf: &[Line]) -> Result<usize, ParseError> {
+    elf.iter()
+        .map(|line| line.parse(context("calories", unsigned::<usize>)))
+        .sum()
+}
+
+impl Generator for Day01 {
+    /// Lists `size` elves carrying 1 to 10 items of up to 20000 calories each.
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let mut input = String::new();
+        let mut totals = Vec::new();
+        for elf in 0..size {
+            if elf > 0 {
+                input.push('\n');
+            }
+            let mut total = 0;
+            for _ in 0..rng.range(1..11) {
+                let calories = rng.range(1..20001);
+                input.push_str(&format!("{calories}\n"));
+                total += calories;
+            }
+            totals.push(total);
+        }
+        totals.sort_unstable_by(|a, b| b.cmp(a));
+        Generated {
+            input,
+            part1: totals.first().map(ToString::to_string),
+            part2: Some(totals.iter().take(3).sum::<usize>().to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{check_example, Params, Solution};
+
+    use crate::{Config, Day01};
+
+    #[test]
+    fn solves_example() {
+        check_example::<Day01>().unwrap();
+    }
+
+    #[test]
+    fn sums_configured_number_of_elves() {
+        let elves = Day01::parse("1000\n\n3000\n\n2000\n\n500\n").unwrap();
+        assert_eq!(Day01::part2(&elves, &Config::default()).unwrap(), 6000);
+
+        let params = Params::parse("top = 2").unwrap();
+        let config = <Config as aoc_common::Config>::from_params(&params).unwrap();
+        assert_eq!(Day01::part2(&elves, &config).unwrap(), 5000);
+    }
+
+    #[test]
+    fn reports_invalid_calories() {
+        let err = Day01::parse("1000\n2000\n\n3000\n4x00\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "input:5:2: Expected end of line, found x00."
+        );
+    }
+
+    #[test]
+    fn handles_crlf_input() {
+        let input = "\u{feff}1000\r\n2000\r\n\r\n3000 \r\n\r\n\r\n";
+        let elves = Day01::parse(input).unwrap();
+        assert_eq!(elves, [3000, 3000]);
+        assert_eq!(Day01::part1(&elves, &Config::default()).unwrap(), 3000);
+    }
+}
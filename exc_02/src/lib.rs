@@ -0,0 +1,285 @@
+//! Day 2: Rock Paper Scissors.
+//!
+//! Each line of the strategy guide holds the opponent's [`Shape`] and a second column,
+//! read as our shape in part 1 and as the desired result in part 2.
+
+use std::io::BufRead;
+
+use aoc_common::{
+    example, lines,
+    nom::{
+        branch::alt,
+        character::complete::{char, space1},
+        combinator::value,
+        error::context,
+        sequence::separated_pair,
+        Parser,
+    },
+    parse::IResult,
+    tracing::{debug, trace},
+    Example, Generated, Generator, Result, Rng, Solution,
+};
+
+/// A hand shape, encoded as `A`/`X`, `B`/`Y` and `C`/`Z` in the strategy guide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    /// Worth 1 point, beats [`Shape::Scissor`].
+    Rock,
+    /// Worth 2 points, beats [`Shape::Rock`].
+    Paper,
+    /// Worth 3 points, beats [`Shape::Paper`].
+    Scissor,
+}
+
+/// Shape written with one of `codes`, given in the order rock, paper, scissor.
+fn shape<'a>(codes: [char; 3]) -> impl FnMut(&'a str) -> IResult<'a, Shape> {
+    alt((
+        value(Shape::Rock, char(codes[0])),
+        value(Shape::Paper, char(codes[1])),
+        value(Shape::Scissor, char(codes[2])),
+    ))
+}
+
+impl From<Shape> for u8 {
+    fn from(val: Shape) -> Self {
+        match val {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissor => 3,
+        }
+    }
+}
+
+/// A single round of the strategy guide, one line of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    mine: Shape,
+    theirs: Shape,
+}
+
+impl Round {
+    pub fn new(mine: Shape, theirs: Shape) -> Self {
+        Self { mine, theirs }
+    }
+
+    /// Shape we play.
+    pub fn mine(&self) -> Shape {
+        self.mine
+    }
+
+    /// Shape the opponent plays.
+    pub fn theirs(&self) -> Shape {
+        self.theirs
+    }
+
+    /// Score of the round: the value of our shape plus 0, 3 or 6 for loss, draw or win.
+    pub fn calculate_outcome(&self) -> usize {
+        let value_mine: u8 = self.mine.into();
+
+        let outcome = match self.get_tuple() {
+            (Shape::Rock, Shape::Scissor) => 6,
+            (Shape::Scissor, Shape::Paper) => 6,
+            (Shape::Paper, Shape::Rock) => 6,
+            (Shape::Rock, Shape::Rock) => 3,
+            (Shape::Scissor, Shape::Scissor) => 3,
+            (Shape::Paper, Shape::Paper) => 3,
+            _ => 0,
+        };
+
+        (value_mine as usize) + (outcome as usize)
+    }
+
+    /// Reinterprets our shape as the desired result, `X` loses, `Y` draws and `Z` wins,
+    /// and returns the round we have to play for it.
+    pub fn calculate_alternative(&self) -> Round {
+        match self.get_tuple() {
+            (Shape::Paper, theirs) => Round::new(theirs, theirs),
+            (Shape::Rock, Shape::Scissor) => Round::new(Shape::Paper, Shape::Scissor),
+            (Shape::Rock, Shape::Paper) => Round::new(Shape::Rock, Shape::Paper),
+            (Shape::Rock, Shape::Rock) => Round::new(Shape::Scissor, Shape::Rock),
+            (Shape::Scissor, Shape::Scissor) => Round::new(Shape::Rock, Shape::Scissor),
+            (Shape::Scissor, Shape::Paper) => Round::new(Shape::Scissor, Shape::Paper),
+            (Shape::Scissor, Shape::Rock) => Round::new(Shape::Paper, Shape::Rock),
+        }
+    }
+
+    fn get_tuple(&self) -> (Shape, Shape) {
+        (self.mine, self.theirs)
+    }
+}
+
+/// A round like `A Y`, the opponent's shape first.
+fn round(input: &str) -> IResult<'_, Round> {
+    separated_pair(
+        context("A, B or C", shape(['A', 'B', 'C'])),
+        space1,
+        context("X, Y or Z", shape(['X', 'Y', 'Z'])),
+    )
+    .map(|(theirs, mine)| Round::new(mine, theirs))
+    .parse(input)
+}
+
+/// Solution of day 2, scoring the rock paper scissors strategy guide.
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const EXAMPLE: Example = example!();
+
+    type Input = Vec<Round>;
+    type Config = ();
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::read(input.as_bytes())
+    }
+
+    fn read<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let mut rounds = Vec::new();
+        for line in lines(reader) {
+            let line = line?;
+            if !line.text.trim().is_empty() {
+                rounds.push(line.parse(round)?);
+            }
+        }
+        debug!(rounds = rounds.len(), "parsed strategy guide");
+        Ok(rounds)
+    }
+
+    fn part1(rounds: &Self::Input, _config: &Self::Config) -> Result<Self::Part1> {
+        Ok(rounds
+            .iter()
+            .map(|round| {
+                let outcome = round.calculate_outcome();
+                trace!(?round, outcome, "played round");
+                outcome
+            })
+            .sum())
+    }
+
+    fn part2(rounds: &Self::Input, _config: &Self::Config) -> Result<Self::Part2> {
+        Ok(rounds
+            .iter()
+            .map(|round| round.calculate_alternative())
+            .map(|round| {
+                let outcome = round.calculate_outcome();
+                trace!(?round, outcome, "played round");
+                outcome
+            })
+            .sum())
+    }
+}
+
+impl Generator for Day02 {
+    /// Writes a strategy guide of `size` random rounds.
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let mut input = String::new();
+        let (mut part1, mut part2) = (0, 0);
+        for _ in 0..size {
+            // shapes and outcomes counted from 0, each shape beats the one before it
+            let (opponent, column) = (rng.range(0..3), rng.range(0..3));
+            input.push_str(&format!(
+                "{} {}\n",
+                char::from(b'A' + opponent as u8),
+                char::from(b'X' + column as u8)
+            ));
+            part1 += column + 1 + 3 * ((column + 4 - opponent) % 3);
+            let shape = (opponent + column + 2) % 3;
+            part2 += shape + 1 + 3 * column;
+        }
+        Generated {
+            input,
+            part1: Some(part1.to_string()),
+            part2: Some(part2.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{check_example, Solution};
+
+    use crate::{Day02, Round, Shape};
+
+    #[test]
+    fn solves_example() {
+        check_example::<Day02>().unwrap();
+    }
+
+    #[test]
+    fn parse_values() {
+        let rounds = Day02::parse(Day02::EXAMPLE.input).unwrap();
+        assert_eq!(
+            rounds,
+            vec![
+                Round::new(Shape::Paper, Shape::Rock),
+                Round::new(Shape::Rock, Shape::Paper),
+                Round::new(Shape::Scissor, Shape::Scissor)
+            ]
+        )
+    }
+
+    #[test]
+    fn reports_unknown_shape() {
+        let err = Day02::parse("A Y\nB Q\nC Z").unwrap_err();
+        assert_eq!(err.to_string(), "input:2:3: Expected X, Y or Z.");
+    }
+
+    #[test]
+    fn calculates_outcome() {
+        let rounds = vec![
+            Round::new(Shape::Paper, Shape::Rock),
+            Round::new(Shape::Rock, Shape::Paper),
+            Round::new(Shape::Scissor, Shape::Scissor),
+        ];
+        let result: usize = rounds
+            .into_iter()
+            .map(|round| round.calculate_outcome())
+            .sum();
+        assert_eq!(result, 15);
+    }
+
+    #[test]
+    fn calculates_alternative_1() {
+        let round = Round::new(Shape::Paper, Shape::Rock);
+        let alternative = round.calculate_alternative();
+        assert_eq!(alternative, Round::new(Shape::Rock, Shape::Rock));
+    }
+
+    #[test]
+    fn calculates_alternative_2() {
+        let round = Round::new(Shape::Rock, Shape::Paper);
+        let alternative = round.calculate_alternative();
+        assert_eq!(alternative, Round::new(Shape::Rock, Shape::Paper));
+    }
+
+    #[test]
+    fn calculates_alternative_3() {
+        let round = Round::new(Shape::Scissor, Shape::Scissor);
+        let alternative = round.calculate_alternative();
+        assert_eq!(alternative, Round::new(Shape::Rock, Shape::Scissor));
+    }
+
+    #[test]
+    fn calculates_alternative() {
+        let rounds = vec![
+            Round::new(Shape::Paper, Shape::Rock),
+            Round::new(Shape::Rock, Shape::Paper),
+            Round::new(Shape::Scissor, Shape::Scissor),
+        ];
+        let result: usize = rounds
+            .into_iter()
+            .map(|round| round.calculate_alternative())
+            .map(|round| round.calculate_outcome())
+            .sum();
+        assert_eq!(result, 12);
+    }
+
+    #[test]
+    fn handles_crlf_input() {
+        let rounds = Day02::parse("\u{feff}A Y\r\nB X\r\nC Z\r\n\r\n").unwrap();
+        assert_eq!(Day02::part1(&rounds, &()).unwrap(), 15);
+        assert_eq!(Day02::part2(&rounds, &()).unwrap(), 12);
+    }
+}
@@ -0,0 +1,297 @@
+//! Day 3: Rucksack Reorganization.
+//!
+//! Each line lists the [`Item`]s of one [`Bag`], the first half belonging to the left and
+//! the second half to the right compartment.
+
+use std::io::BufRead;
+
+use aoc_common::{
+    example, lines,
+    nom::{character::complete::alpha1, combinator::verify, error::context, Parser},
+    parse::IResult,
+    tracing::{debug, trace},
+    Error, Example, Generated, Generator, Params, Result, Rng, Solution,
+};
+
+/// Parameters of the puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// Number of elves sharing a badge in part 2.
+    pub group_size: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { group_size: 3 }
+    }
+}
+
+impl aoc_common::Config for Config {
+    const KEYS: &'static [&'static str] = &["group_size"];
+
+    fn from_params(params: &Params) -> Result<Self> {
+        let default = Config::default();
+        let group_size = params.get("group_size", default.group_size)?;
+        if group_size == 0 {
+            return Err(Error::Usage("A group needs at least one elf.".into()));
+        }
+        Ok(Config { group_size })
+    }
+}
+
+/// An item type, identified by a letter.
+///
+/// Converting it into `usize` yields its priority: 1 through 26 for `a` to `z` and 27
+/// through 52 for `A` to `Z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Item(char);
+
+impl Item {
+    pub fn new(item: char) -> Self {
+        Item(item)
+    }
+}
+
+impl From<Item> for usize {
+    fn from(item: Item) -> Self {
+        match item.0 {
+            'a'..='z' => (item.0 as usize) - 96,
+            'A'..='Z' => (item.0 as usize) - 38,
+            _ => 0,
+        }
+    }
+}
+
+/// A rucksack with its items split evenly into two compartments.
+#[derive(Debug)]
+pub struct Bag {
+    left_compartment: Vec<Item>,
+    right_compartment: Vec<Item>,
+}
+
+impl Bag {
+    pub fn new(left: Vec<Item>, right: Vec<Item>) -> Self {
+        Bag {
+            left_compartment: left,
+            right_compartment: right,
+        }
+    }
+
+    /// Items present in both compartments.
+    pub fn find_duplicates(&self) -> Vec<Item> {
+        let mut result = self.left_compartment.clone();
+        result.retain(|&item| self.right_compartment.contains(&item));
+        result.dedup();
+        result
+    }
+
+    /// Items carried by every one of `bags`, e.g. the badge of a group of elves.
+    pub fn find_all_duplicates(bags: Vec<&Bag>) -> Vec<Item> {
+        let mut result: Vec<Item> = bags[0].into();
+        for bag in bags.iter().skip(1) {
+            result.retain(|&value| Into::<Vec<Item>>::into(*bag).contains(&value));
+        }
+        result.dedup();
+        result
+    }
+}
+
+impl From<&Bag> for Vec<Item> {
+    fn from(bag: &Bag) -> Self {
+        let mut result = bag.left_compartment.clone();
+        result.extend(&bag.right_compartment);
+        result
+    }
+}
+
+/// Solution of day 3, prioritizing rucksack items.
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const EXAMPLE: Example = example!();
+
+    type Input = Vec<Bag>;
+    type Config = Config;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::read(input.as_bytes())
+    }
+
+    fn read<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let mut bags = Vec::new();
+        for line in lines(reader) {
+            let line = line?;
+            if !line.text.trim().is_empty() {
+                bags.push(line.parse(bag)?);
+            }
+        }
+        debug!(bags = bags.len(), "parsed bags");
+        Ok(bags)
+    }
+
+    fn part1(bags: &Self::Input, _config: &Self::Config) -> Result<Self::Part1> {
+        Ok(bags
+            .iter()
+            .flat_map(|bag| {
+                let duplicates = bag.find_duplicates();
+                trace!(?duplicates, "found items in both compartments");
+                duplicates
+            })
+            .map(Into::<usize>::into)
+            .sum())
+    }
+
+    fn part2(bags: &Self::Input, config: &Self::Config) -> Result<Self::Part2> {
+        let mut groups: usize = 0;
+        for chunk in bags.chunks(config.group_size) {
+            let badges = Bag::find_all_duplicates(chunk.iter().collect());
+            let group: usize = badges.into_iter().map(Into::<usize>::into).sum();
+            groups += group;
+        }
+        Ok(groups)
+    }
+}
+
+impl Generator for Day03 {
+    /// Packs `size` rucksacks, rounded up to whole groups of three elves.
+    ///
+    /// Every group draws its own letters: a badge, one shared item per rucksack and a
+    /// private pool of items per elf, split between the two compartments. This way each
+    /// rucksack has exactly one item in both compartments and each group exactly one item
+    /// in common.
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let mut letters: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+        let (mut part1, mut part2) = (0, 0);
+        let mut input = String::new();
+        for _ in 0..size.div_ceil(3) {
+            rng.shuffle(&mut letters);
+            let badge = letters[0];
+            part2 += usize::from(Item(badge));
+            for (elf, pool) in letters[4..].chunks(16).enumerate() {
+                let shared = letters[1 + elf];
+                part1 += usize::from(Item(shared));
+                let length = rng.range(2..16);
+                let (left_pool, right_pool) = pool.split_at(8);
+                let mut left = vec![badge, shared];
+                left.extend((2..length).map(|_| *rng.pick(left_pool)));
+                let mut right = vec![shared];
+                right.extend((1..length).map(|_| *rng.pick(right_pool)));
+                rng.shuffle(&mut left);
+                rng.shuffle(&mut right);
+                input.extend(left.into_iter().chain(right));
+                input.push('\n');
+            }
+        }
+        Generated {
+            input,
+            part1: Some(part1.to_string()),
+            part2: Some(part2.to_string()),
+        }
+    }
+}
+
+/// A rucksack written as the letters of its items, half of them in each compartment.
+fn bag(input: &str) -> IResult<'_, Bag> {
+    let letters = verify(alpha1, |letters: &str| letters.len().is_multiple_of(2));
+    context("an even number of item letters", letters)
+        .map(|letters: &str| {
+            let (left, right) = letters.split_at(letters.len() / 2);
+            Bag::new(
+                left.chars().map(Item).collect(),
+                right.chars().map(Item).collect(),
+            )
+        })
+        .parse(input)
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{check_example, Solution};
+
+    use crate::{Config, Day03, Item};
+
+    #[test]
+    fn solves_example() {
+        check_example::<Day03>().unwrap();
+    }
+
+    #[test]
+    fn value_lower_a() {
+        let item = Item::new('a');
+        let value: usize = item.into();
+        assert_eq!(value, 1);
+    }
+
+    #[test]
+    fn value_lower_z() {
+        let item = Item::new('z');
+        let value: usize = item.into();
+        assert_eq!(value, 26);
+    }
+
+    #[test]
+    fn value_upper_a() {
+        let item = Item::new('A');
+        let value: usize = item.into();
+        assert_eq!(value, 27);
+    }
+
+    #[test]
+    fn value_upper_z() {
+        let item = Item::new('Z');
+        let value: usize = item.into();
+        assert_eq!(value, 52);
+    }
+
+    #[test]
+    fn parse_sample() {
+        let input = Day03::EXAMPLE.input;
+
+        let bags = Day03::parse(input).unwrap();
+
+        let result = Day03::part1(&bags, &Config::default()).unwrap();
+        assert_eq!(result, 157);
+    }
+
+    #[test]
+    fn parse_sample_by_three() {
+        let input = Day03::EXAMPLE.input;
+
+        let bags = Day03::parse(input).unwrap();
+
+        let result = Day03::part2(&bags, &Config::default()).unwrap();
+        assert_eq!(result, 70);
+    }
+
+    #[test]
+    fn handles_crlf_input() {
+        let input = "\u{feff}vJrwpWtwJgWrhcsFMMfFFhFp\r\n\
+                     jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\r\n\
+                     PmmdzqPrVvPwwTWBwg\r\n\
+                     wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\r\n\
+                     ttgJtRGJQctTZtZT\r\n\
+                     CrZsJsPPZsGzwwsLwLmpwMDw\r\n\r\n";
+        let bags = Day03::parse(input).unwrap();
+        // a trailing line ending must not add an empty rucksack to the last group
+        assert_eq!(bags.len(), 6);
+        assert_eq!(Day03::part1(&bags, &Config::default()).unwrap(), 157);
+        assert_eq!(Day03::part2(&bags, &Config::default()).unwrap(), 70);
+    }
+
+    #[test]
+    fn reports_invalid_items() {
+        let err = Day03::parse("vJrwpWtwJgWrhcsFMMfFFhFp\nPmmdzq7rVv\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "input:2:7: Expected end of line, found 7rVv."
+        );
+        let err = Day03::parse("vJrwpWtwJgWrhcsFMMfFFhFp\nPmm\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "input:2:1: Expected an even number of item letters."
+        );
+    }
+}
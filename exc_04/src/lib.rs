@@ -0,0 +1,187 @@
+//! Day 4: Camp Cleanup.
+//!
+//! Each line holds a [`Pair`] of [`SectionAssignment`]s, such as `2-4,6-8`.
+
+use std::io::BufRead;
+
+use aoc_common::{
+    example, lines,
+    nom::{character::complete::char, sequence::separated_pair, Parser},
+    parse::{range, IResult},
+    tracing::debug,
+    Example, Generated, Generator, Result, Rng, Solution,
+};
+
+/// Inclusive range of section IDs assigned to an elf, written as `2-4`.
+#[derive(Debug, Clone, Copy)]
+pub struct SectionAssignment {
+    lower_end: u8,
+    upper_end: u8,
+}
+
+impl SectionAssignment {
+    pub fn new(lower_end: u8, upper_end: u8) -> Self {
+        SectionAssignment {
+            lower_end,
+            upper_end,
+        }
+    }
+
+    /// First section of the range.
+    pub fn lower_end(&self) -> u8 {
+        self.lower_end
+    }
+
+    /// Last section of the range.
+    pub fn upper_end(&self) -> u8 {
+        self.upper_end
+    }
+}
+
+/// A range of sections like `2-4`.
+fn section_assignment(input: &str) -> IResult<'_, SectionAssignment> {
+    range
+        .map(|range| SectionAssignment::new(*range.start(), *range.end()))
+        .parse(input)
+}
+
+/// The assignments of two elves cleaning up together, one line of the input.
+#[derive(Debug)]
+pub struct Pair {
+    left: SectionAssignment,
+    right: SectionAssignment,
+}
+
+impl Pair {
+    pub fn new(left: SectionAssignment, right: SectionAssignment) -> Self {
+        Pair { left, right }
+    }
+
+    /// Whether one assignment fully contains the other.
+    pub fn is_contained(&self) -> bool {
+        (self.left.lower_end <= self.right.lower_end && self.left.upper_end >= self.right.upper_end)
+            || (self.right.lower_end <= self.left.lower_end
+                && self.right.upper_end >= self.left.upper_end)
+    }
+
+    /// Whether the assignments share at least one section.
+    pub fn is_overlapping(&self) -> bool {
+        (self.left.lower_end <= self.right.lower_end && self.left.upper_end >= self.right.lower_end)
+            || (self.right.lower_end <= self.left.lower_end
+                && self.right.upper_end >= self.left.lower_end)
+    }
+}
+
+/// Two ranges separated by a comma, like `2-4,6-8`.
+fn pair(input: &str) -> IResult<'_, Pair> {
+    separated_pair(section_assignment, char(','), section_assignment)
+        .map(|(left, right)| Pair::new(left, right))
+        .parse(input)
+}
+
+/// Solution of day 4, finding overlapping section assignments.
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const EXAMPLE: Example = example!();
+
+    type Input = Vec<Pair>;
+    type Config = ();
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::read(input.as_bytes())
+    }
+
+    fn read<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let mut pairs = Vec::new();
+        for line in lines(reader) {
+            let line = line?;
+            if !line.text.trim().is_empty() {
+                pairs.push(line.parse(pair)?);
+            }
+        }
+        debug!(pairs = pairs.len(), "parsed section assignments");
+        Ok(pairs)
+    }
+
+    fn part1(pairs: &Self::Input, _config: &Self::Config) -> Result<Self::Part1> {
+        Ok(pairs.iter().filter(|pair| pair.is_contained()).count())
+    }
+
+    fn part2(pairs: &Self::Input, _config: &Self::Config) -> Result<Self::Part2> {
+        Ok(pairs.iter().filter(|pair| pair.is_overlapping()).count())
+    }
+}
+
+impl Generator for Day04 {
+    /// Writes `size` pairs of section ranges between 1 and 99.
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let mut input = String::new();
+        let (mut contained, mut overlapping) = (0, 0);
+        for _ in 0..size {
+            let mut range = || {
+                let start = rng.range(1..100);
+                (start, rng.range(start..100))
+            };
+            let ((a, b), (c, d)) = (range(), range());
+            input.push_str(&format!("{a}-{b},{c}-{d}\n"));
+            if (a <= c && d <= b) || (c <= a && b <= d) {
+                contained += 1;
+            }
+            if a <= d && c <= b {
+                overlapping += 1;
+            }
+        }
+        Generated {
+            input,
+            part1: Some(contained.to_string()),
+            part2: Some(overlapping.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{check_example, Solution};
+
+    use crate::Day04;
+
+    #[test]
+    fn solves_example() {
+        check_example::<Day04>().unwrap();
+    }
+
+    #[test]
+    fn processes_sample() {
+        let input = Day04::EXAMPLE.input;
+        let pairs = Day04::parse(input).unwrap();
+        let count = Day04::part1(&pairs, &()).unwrap();
+        assert_eq!(count, 2);
+
+        let count2 = Day04::part2(&pairs, &()).unwrap();
+        assert_eq!(count2, 4);
+    }
+
+    #[test]
+    fn reports_invalid_section() {
+        let err = Day04::parse("2-4,6-8\n2-3,4-x\n").unwrap_err();
+        assert_eq!(err.to_string(), "input:2:7: Expected a number.");
+
+        let err = Day04::parse("2-4,6-8,1-1").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "input:1:8: Expected end of line, found ,1-1."
+        );
+    }
+
+    #[test]
+    fn handles_crlf_input() {
+        let input = "\u{feff}2-4,6-8\r\n2-3,4-5\r\n5-7,7-9\r\n2-8,3-7\r\n6-6,4-6\r\n2-6,4-8\r\n";
+        let pairs = Day04::parse(input).unwrap();
+        assert_eq!(Day04::part1(&pairs, &()).unwrap(), 2);
+        assert_eq!(Day04::part2(&pairs, &()).unwrap(), 4);
+    }
+}
@@ -0,0 +1,654 @@
+//! Day 5: Supply Stacks.
+//!
+//! The input lists the [`Instruction`]s the crane operates on a [`Stock`] of crates. The
+//! answer of both parts is the crate on top of each [`Slot`] after all moves.
+
+use std::{fmt::Display, io::BufRead, str::FromStr};
+
+use aoc_common::{
+    example, lines,
+    nom::{
+        branch::alt,
+        bytes::complete::tag,
+        character::complete::{char, satisfy, space0, space1},
+        combinator::{cut, value, verify},
+        error::context,
+        multi::{many1, separated_list1},
+        sequence::{delimited, pair, preceded, tuple},
+        Parser,
+    },
+    parse::{keyword, parse_all, unsigned, IResult},
+    tracing::{debug, trace},
+    Error, Example, Generated, Generator, Params, ParseError, Result, Rng, Solution,
+};
+
+/// A stack of crates, the last item is the one on top.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Slot {
+    items: Vec<char>,
+}
+
+impl Slot {
+    pub fn new(items: Vec<char>) -> Self {
+        Slot { items }
+    }
+    pub fn pop(&mut self) -> Option<char> {
+        self.items.pop()
+    }
+
+    /// Removes up to `no_of_items` crates, the topmost crate comes first.
+    pub fn pop_n(&mut self, no_of_items: usize) -> Vec<char> {
+        let mut result = Vec::with_capacity(no_of_items);
+        for _ in 0..no_of_items {
+            if let Some(c) = self.pop() {
+                result.push(c);
+            }
+        }
+        result
+    }
+    pub fn push(&mut self, item: char) {
+        self.items.push(item)
+    }
+
+    /// Pushes `items` in order, so the last one ends up on top.
+    pub fn push_n(&mut self, items: &[char]) {
+        for item in items {
+            self.push(item.to_owned());
+        }
+    }
+
+    /// Crate on top of the stack, if there is one.
+    pub fn top(&self) -> Option<char> {
+        self.items.last().copied()
+    }
+    #[must_use]
+    pub fn items(&self) -> &[char] {
+        self.items.as_ref()
+    }
+}
+
+impl Display for Slot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for item in self.items().iter() {
+            f.write_str(&format!("{} ", item))?;
+        }
+        f.write_str("")
+    }
+}
+
+/// All stacks of crates in the supply depot, numbered from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stock {
+    slots: Vec<Slot>,
+}
+
+impl Stock {
+    pub fn new(slots: Vec<Slot>) -> Self {
+        Stock { slots }
+    }
+
+    /// Stacks up the `rows` of a drawing, given top to bottom, onto `slots` slots.
+    ///
+    /// Each row holds the crate above each slot, if there is one.
+    pub fn from_drawing(rows: &[Vec<Option<char>>], slots: usize) -> Self {
+        let mut stock = Stock::new(vec![Slot::new(Vec::new()); slots]);
+        for row in rows.iter().rev() {
+            for (slot, item) in stock.slots.iter_mut().zip(row) {
+                if let Some(item) = item {
+                    slot.push(*item);
+                }
+            }
+        }
+        stock
+    }
+
+    /// Moves `no_of_items` crates one at a time, reversing their order (CrateMover 9000).
+    pub fn shift(&mut self, from: usize, to: usize, no_of_items: usize) {
+        let mut chars: Vec<char> = Vec::new();
+        if let Some(from_slot) = self.slots.get_mut(from - 1) {
+            chars = from_slot.pop_n(no_of_items);
+        }
+        if let Some(to_slot) = self.slots.get_mut(to - 1) {
+            to_slot.push_n(&chars);
+        }
+    }
+
+    /// Moves `no_of_items` crates at once, keeping their order (CrateMover 9001).
+    pub fn bulk_move(&mut self, from: usize, to: usize, no_of_items: usize) {
+        let mut chars: Vec<char> = Vec::new();
+        if let Some(from_slot) = self.slots.get_mut(from - 1) {
+            chars = from_slot.pop_n(no_of_items);
+        }
+        chars.reverse();
+        if let Some(to_slot) = self.slots.get_mut(to - 1) {
+            to_slot.push_n(&chars);
+        }
+    }
+
+    /// Ensures both slots referenced by `instruction` exist in this stock and the slot to
+    /// take from holds enough crates.
+    pub fn check(&self, instruction: &Instruction) -> Result<()> {
+        for slot in [instruction.from_slot, instruction.to_slot] {
+            if slot == 0 || slot > self.slots.len() {
+                return Err(Error::domain(format!(
+                    "Stock has {} slots, but instruction refers to slot {slot}.",
+                    self.slots.len()
+                )));
+            }
+        }
+        let available = self.slots[instruction.from_slot - 1].items().len();
+        if instruction.no_of_items > available {
+            return Err(Error::domain(format!(
+                "Slot {} holds {available} crates, but instruction moves {}.",
+                instruction.from_slot, instruction.no_of_items
+            )));
+        }
+        Ok(())
+    }
+
+    /// Crates on top of each stack, skipping empty ones.
+    pub fn top_items(&self) -> String {
+        self.slots.iter().filter_map(Slot::top).collect()
+    }
+
+    #[must_use]
+    pub fn slots(&self) -> &[Slot] {
+        self.slots.as_ref()
+    }
+}
+
+impl Display for Stock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for slot in self.slots().iter() {
+            f.write_str(&format!("| {}|\n", slot))?;
+        }
+        f.write_str("")
+    }
+}
+
+/// Reads a stock written as one group of crates per slot, bottom to top, separated by
+/// commas, e.g. `ZN,MCD,P`.
+impl FromStr for Stock {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        input
+            .split(',')
+            .map(
+                |slot| match slot.trim().chars().find(|c| !c.is_ascii_alphabetic()) {
+                    Some(c) => Err(format!("Crate {c} is not a letter.")),
+                    None => Ok(Slot::new(slot.trim().chars().collect())),
+                },
+            )
+            .collect::<Result<_, _>>()
+            .map(Stock::new)
+    }
+}
+
+/// A rearrangement step, written as `move 3 from 1 to 2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    no_of_items: usize,
+    from_slot: usize,
+    to_slot: usize,
+}
+
+/// Parsed puzzle input: the stacks as drawn and the steps of the rearrangement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Procedure {
+    stock: Option<Stock>,
+    instructions: Vec<Instruction>,
+}
+
+impl Procedure {
+    /// Stacks drawn at the top of the input, if there is a drawing.
+    pub fn stock(&self) -> Option<&Stock> {
+        self.stock.as_ref()
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        self.instructions.as_ref()
+    }
+
+    /// Stacks to rearrange, those of `config` take precedence over the drawing.
+    fn initial_stock(&self, config: &Config) -> Result<Stock> {
+        config
+            .stock
+            .as_ref()
+            .or(self.stock.as_ref())
+            .cloned()
+            .ok_or_else(|| {
+                Error::domain("Input has no drawing of the stacks, set the stock parameter")
+            })
+    }
+}
+
+/// Parameters of the puzzle.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// Stacks to start with instead of the ones drawn in the input.
+    pub stock: Option<Stock>,
+}
+
+impl aoc_common::Config for Config {
+    const KEYS: &'static [&'static str] = &["stock"];
+
+    fn from_params(params: &Params) -> Result<Self> {
+        let stock = params
+            .get_str("stock")
+            .map(|stock| {
+                stock
+                    .parse()
+                    .map_err(|err| Error::Usage(format!("Invalid value {stock} for stock: {err}")))
+            })
+            .transpose()?;
+        Ok(Config { stock })
+    }
+}
+
+impl Instruction {
+    pub fn no_of_items(&self) -> usize {
+        self.no_of_items
+    }
+
+    /// 1-based number of the slot to take crates from.
+    pub fn from_slot(&self) -> usize {
+        self.from_slot
+    }
+
+    /// 1-based number of the slot to put crates onto.
+    pub fn to_slot(&self) -> usize {
+        self.to_slot
+    }
+}
+
+/// A line of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Entry {
+    /// Crates drawn at one height, like `    [D]`, `None` where a slot has no crate.
+    Row(Vec<Option<char>>),
+    /// Numbers of the slots below the drawing, like ` 1   2   3`.
+    Labels(usize),
+    Move(Instruction),
+}
+
+/// A crate like `[A]` or three spaces where there is none.
+fn item(input: &str) -> IResult<'_, Option<char>> {
+    let letter = context("a crate like [A]", satisfy(|c| c.is_ascii_alphabetic()));
+    context(
+        "a crate like [A]",
+        alt((
+            delimited(char('['), letter, char(']')).map(Some),
+            value(None, tag("   ")),
+        )),
+    )(input)
+}
+
+/// A step like `move 3 from 1 to 2`, slots are numbered from 1.
+fn instruction(input: &str) -> IResult<'_, Instruction> {
+    let slot = || context("a slot number", verify(unsigned, |slot: &usize| *slot > 0));
+    let steps = tuple((
+        context("a crate count", unsigned),
+        preceded(pair(space1, keyword("from")), slot()),
+        preceded(pair(space1, keyword("to")), slot()),
+    ));
+    preceded(keyword("move"), cut(steps))
+        .map(|(no_of_items, from_slot, to_slot)| Instruction {
+            no_of_items,
+            from_slot,
+            to_slot,
+        })
+        .parse(input)
+}
+
+fn entry(input: &str) -> IResult<'_, Entry> {
+    let labels = many1(preceded(space0, unsigned::<usize>));
+    context(
+        "a drawing of crates or a move",
+        alt((
+            preceded(space0, instruction).map(Entry::Move),
+            labels.map(|labels| Entry::Labels(labels.len())),
+            separated_list1(char(' '), cut(item)).map(Entry::Row),
+        )),
+    )(input)
+}
+
+/// Solution of day 5, rearranging stacks of crates.
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const EXAMPLE: Example = example!();
+
+    type Input = Procedure;
+    type Config = Config;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::read(input.as_bytes())
+    }
+
+    fn read<R: BufRead>(reader: R) -> Result<Self::Input> {
+        // rows are kept only until the slot numbers end the drawing
+        let mut rows = Vec::new();
+        let mut stock = None;
+        let mut instructions = Vec::new();
+        for line in lines(reader) {
+            let line = line?;
+            if line.text.trim().is_empty() {
+                continue;
+            }
+            let entry = parse_all(&line.text, entry).map_err(|err| err.at_line(line.number))?;
+            match entry {
+                Entry::Move(instruction) => instructions.push(instruction),
+                _ if stock.is_some() || !instructions.is_empty() => {
+                    return Err(ParseError::new("Expected a move below the drawing.")
+                        .at_line(line.number)
+                        .into())
+                }
+                Entry::Row(row) => rows.push((line.number, row)),
+                Entry::Labels(slots) => {
+                    // crates outside the labelled slots would silently get lost
+                    if let Some((number, row)) = rows.iter().find(|(_, row)| row.len() > slots) {
+                        return Err(ParseError::new(format!(
+                            "Drawing has {slots} slots, but this row spans {}.",
+                            row.len()
+                        ))
+                        .at_line(*number)
+                        .into());
+                    }
+                    let drawing: Vec<_> = rows.drain(..).map(|(_, row)| row).collect();
+                    stock = Some(Stock::from_drawing(&drawing, slots));
+                }
+            }
+        }
+        debug!(
+            instructions = instructions.len(),
+            drawing = stock.is_some(),
+            "parsed procedure"
+        );
+        Ok(Procedure {
+            stock,
+            instructions,
+        })
+    }
+
+    fn part1(procedure: &Self::Input, config: &Self::Config) -> Result<Self::Part1> {
+        let mut stock = procedure.initial_stock(config)?;
+        for instruction in &procedure.instructions {
+            stock.check(instruction)?;
+            stock.shift(
+                instruction.from_slot,
+                instruction.to_slot,
+                instruction.no_of_items,
+            );
+            trace!(
+                count = instruction.no_of_items,
+                from = instruction.from_slot,
+                to = instruction.to_slot,
+                tops = %stock.top_items(),
+                "moved crates one by one"
+            );
+        }
+        Ok(stock.top_items())
+    }
+
+    fn part2(procedure: &Self::Input, config: &Self::Config) -> Result<Self::Part2> {
+        let mut stock = procedure.initial_stock(config)?;
+        for instruction in &procedure.instructions {
+            stock.check(instruction)?;
+            stock.bulk_move(
+                instruction.from_slot,
+                instruction.to_slot,
+                instruction.no_of_items,
+            );
+            trace!(
+                count = instruction.no_of_items,
+                from = instruction.from_slot,
+                to = instruction.to_slot,
+                tops = %stock.top_items(),
+                "moved crates at once"
+            );
+        }
+        Ok(stock.top_items())
+    }
+}
+
+impl Generator for Day05 {
+    /// Draws nine stacks of crates, followed by `size` moves that never take more crates
+    /// from a stack than it holds.
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let stacks: Vec<Vec<char>> = (0..9)
+            .map(|_| {
+                let height = rng.range(1..9);
+                (0..height)
+                    .map(|_| char::from(b'A' + rng.range(0..26) as u8))
+                    .collect()
+            })
+            .collect();
+
+        let mut input = String::new();
+        let height = stacks.iter().map(Vec::len).max().unwrap_or_default();
+        for level in (0..height).rev() {
+            let row: Vec<_> = stacks
+                .iter()
+                .map(|stack| {
+                    stack
+                        .get(level)
+                        .map_or("   ".to_owned(), |c| format!("[{c}]"))
+                })
+                .collect();
+            input.push_str(row.join(" ").trim_end());
+            input.push('\n');
+        }
+        let labels: Vec<_> = (1..=stacks.len()).map(|slot| format!(" {slot} ")).collect();
+        input.push_str(labels.join(" ").trim_end());
+        input.push_str("\n\n");
+
+        // crates moved one at a time and all at once, as in part 1 and 2
+        let (mut single, mut multiple) = (stacks.clone(), stacks);
+        for _ in 0..size {
+            let from = loop {
+                let from = rng.range(0..single.len());
+                if !single[from].is_empty() {
+                    break from;
+                }
+            };
+            let to = (from + rng.range(1..single.len())) % single.len();
+            let count = rng.range(1..single[from].len() + 1);
+            input.push_str(&format!("move {count} from {} to {}\n", from + 1, to + 1));
+            for _ in 0..count {
+                let item = single[from].pop().expect("stack holds enough crates");
+                single[to].push(item);
+            }
+            let start = multiple[from].len() - count;
+            let items = multiple[from].split_off(start);
+            multiple[to].extend(items);
+        }
+        let tops =
+            |stacks: Vec<Vec<char>>| stacks.iter().filter_map(|stack| stack.last()).collect();
+        Generated {
+            input,
+            part1: Some(tops(single)),
+            part2: Some(tops(multiple)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{check_example, Solution};
+
+    use crate::{Config, Day05, Instruction, Slot, Stock};
+
+    #[test]
+    fn solves_example() {
+        check_example::<Day05>().unwrap();
+    }
+
+    #[test]
+    fn reads_drawing_of_stacks() {
+        let input = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\n";
+        let procedure = Day05::parse(input).unwrap();
+        let stock = procedure.stock().unwrap();
+        assert_eq!(stock.to_string(), "| Z N |\n| M C D |\n| P |\n");
+        assert_eq!(procedure.instructions().len(), 1);
+        assert_eq!(Day05::part1(&procedure, &Config::default()).unwrap(), "DCP");
+
+        let config = Config {
+            stock: Some("ZN,MC,PD".parse().unwrap()),
+        };
+        assert_eq!(Day05::part1(&procedure, &config).unwrap(), "CMD");
+
+        let err = Day05::parse("[Z] [-]\n 1   2 \n").unwrap_err();
+        assert_eq!(err.to_string(), "input:1:6: Expected a crate like [A].");
+    }
+
+    #[test]
+    fn parses_instructions() {
+        let input = "move 1 from 2 to 1\nmove 13 from 1 to 3\n";
+        let procedure = Day05::parse(input).unwrap();
+        assert_eq!(procedure.stock(), None);
+        assert_eq!(
+            procedure.instructions(),
+            [
+                Instruction {
+                    no_of_items: 1,
+                    from_slot: 2,
+                    to_slot: 1
+                },
+                Instruction {
+                    no_of_items: 13,
+                    from_slot: 1,
+                    to_slot: 3
+                }
+            ]
+        );
+
+        let err = Day05::parse("move 1 from 2 to 1\nmove 3 from 0 to 3").unwrap_err();
+        assert_eq!(err.to_string(), "input:2:13: Expected a slot number.");
+        let err = Day05::parse("move 1 frm 2 to 1").unwrap_err();
+        assert_eq!(err.to_string(), "input:1:8: Expected from.");
+        let err = Day05::parse("move 1 from 2 to 1\n[A]\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "input:2: Expected a move below the drawing."
+        );
+    }
+
+    #[test]
+    fn processes_sample_part1() {
+        /*
+                [D]
+            [N] [C]
+            [Z] [M] [P]
+             1   2   3
+
+            move 1 from 2 to 1
+            move 3 from 1 to 3
+            move 2 from 2 to 1
+            move 1 from 1 to 2
+        */
+        let slot_1 = Slot::new(vec!['Z', 'N']);
+        let slot_2 = Slot::new(vec!['M', 'C', 'D']);
+        let slot_3 = Slot::new(vec!['P']);
+
+        let mut stock = Stock::new(vec![slot_1, slot_2, slot_3]);
+        assert_eq!(stock.to_string(), "| Z N |\n| M C D |\n| P |\n");
+
+        // step 1: move 1 from 2 to 1
+        stock.shift(2, 1, 1);
+        assert_eq!(stock.to_string(), "| Z N D |\n| M C |\n| P |\n");
+
+        // step 2: move 3 from 1 to 3
+        stock.shift(1, 3, 3);
+        assert_eq!(stock.to_string(), "| |\n| M C |\n| P D N Z |\n");
+
+        // step 3: move 2 from 2 to 1
+        stock.shift(2, 1, 2);
+        assert_eq!(stock.to_string(), "| C M |\n| |\n| P D N Z |\n");
+
+        // step 4: move 1 from 1 to 2
+        stock.shift(1, 2, 1);
+        assert_eq!(stock.to_string(), "| C |\n| M |\n| P D N Z |\n");
+        assert_eq!(stock.top_items(), "CMZ");
+    }
+
+    #[test]
+    fn processes_sample_part2() {
+        /*
+                [D]
+            [N] [C]
+            [Z] [M] [P]
+             1   2   3
+
+            move 1 from 2 to 1
+            move 3 from 1 to 3
+            move 2 from 2 to 1
+            move 1 from 1 to 2
+        */
+        let slot_1 = Slot::new(vec!['Z', 'N']);
+        let slot_2 = Slot::new(vec!['M', 'C', 'D']);
+        let slot_3 = Slot::new(vec!['P']);
+
+        let mut stock = Stock::new(vec![slot_1, slot_2, slot_3]);
+        assert_eq!(stock.to_string(), "| Z N |\n| M C D |\n| P |\n");
+
+        // step 1: move 1 from 2 to 1
+        stock.bulk_move(2, 1, 1);
+        assert_eq!(stock.to_string(), "| Z N D |\n| M C |\n| P |\n");
+
+        // step 2: move 3 from 1 to 3
+        stock.bulk_move(1, 3, 3);
+        assert_eq!(stock.to_string(), "| |\n| M C |\n| P Z N D |\n");
+
+        // step 3: move 2 from 2 to 1
+        stock.bulk_move(2, 1, 2);
+        assert_eq!(stock.to_string(), "| M C |\n| |\n| P Z N D |\n");
+
+        // step 4: move 1 from 1 to 2
+        stock.bulk_move(1, 2, 1);
+        assert_eq!(stock.to_string(), "| M |\n| C |\n| P Z N D |\n");
+        assert_eq!(stock.top_items(), "MCD");
+    }
+
+    #[test]
+    fn reports_invalid_moves() {
+        let procedure = Day05::parse("[N] [C]\n 1   2 \n\nmove 5 from 1 to 2\n").unwrap();
+        let err = Day05::part1(&procedure, &Config::default()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Slot 1 holds 1 crates, but instruction moves 5."
+        );
+        assert!(Day05::part2(&procedure, &Config::default()).is_err());
+
+        let procedure = Day05::parse("[N] [C]\n 1   2 \n\nmove 1 from 3 to 2\n").unwrap();
+        let err = Day05::part2(&procedure, &Config::default()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Stock has 2 slots, but instruction refers to slot 3."
+        );
+    }
+
+    #[test]
+    fn reports_crates_outside_the_slots() {
+        let err =
+            Day05::parse("[N] [C]\n[Z] [M] [P]\n 1   2 \n\nmove 1 from 1 to 2\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "input:2: Drawing has 2 slots, but this row spans 3."
+        );
+    }
+
+    #[test]
+    fn handles_crlf_input() {
+        let input = "\u{feff}    [D]    \r\n[N] [C]    \r\n[Z] [M] [P]\r\n 1   2   3 \r\n\r\n\
+                     move 1 from 2 to 1\r\nmove 3 from 1 to 3\r\n\
+                     move 2 from 2 to 1\r\nmove 1 from 1 to 2\r\n\r\n";
+        let procedure = Day05::parse(input).unwrap();
+        assert_eq!(procedure.instructions().len(), 4);
+        assert_eq!(Day05::part1(&procedure, &Config::default()).unwrap(), "CMZ");
+        assert_eq!(Day05::part2(&procedure, &Config::default()).unwrap(), "MCD");
+    }
+}
@@ -0,0 +1,252 @@
+//! Day 6: Tuning Trouble.
+//!
+//! The input is a single datastream, the answers are the positions after the first run of
+//! 4 and 14 distinct characters, see [`Config`].
+
+use std::{collections::HashMap, io::BufRead};
+
+use aoc_common::{
+    chars, example,
+    tracing::{debug, trace},
+    Error, Example, Generated, Generator, Params, Result, Rng, Solution,
+};
+
+/// Parameters of the puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// Number of distinct characters marking the start of a packet.
+    pub packet_window: usize,
+    /// Number of distinct characters marking the start of a message.
+    pub message_window: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            packet_window: 4,
+            message_window: 14,
+        }
+    }
+}
+
+impl aoc_common::Config for Config {
+    const KEYS: &'static [&'static str] = &["packet_window", "message_window"];
+
+    fn from_params(params: &Params) -> Result<Self> {
+        let default = Config::default();
+        let config = Config {
+            packet_window: params.get("packet_window", default.packet_window)?,
+            message_window: params.get("message_window", default.message_window)?,
+        };
+        if config.packet_window == 0 || config.message_window == 0 {
+            return Err(Error::Usage(
+                "A marker needs at least one character.".into(),
+            ));
+        }
+        Ok(config)
+    }
+}
+
+/// Where the first run of distinct characters of each length ends in a datastream.
+///
+/// Scanning the stream once is enough to answer every marker length, so the stream
+/// itself is never stored.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Markers {
+    /// Entry `n - 1` is the number of characters read until `n` distinct ones were in a row.
+    ends: Vec<usize>,
+}
+
+impl Markers {
+    /// Scans the characters of a datastream, whitespace is skipped.
+    pub fn scan(chars: impl IntoIterator<Item = Result<char>>) -> Result<Self> {
+        let mut ends = Vec::new();
+        let mut last_seen = HashMap::new();
+        // position of the first character of the current run of distinct ones
+        let mut start = 0;
+        let mut position = 0;
+        for char in chars {
+            let char = char?;
+            if char.is_whitespace() {
+                continue;
+            }
+            if let Some(previous) = last_seen.insert(char, position) {
+                start = start.max(previous + 1);
+            }
+            position += 1;
+            // runs grow by at most one character per step
+            if position - start > ends.len() {
+                ends.push(position);
+                trace!(length = ends.len(), position, "found longer distinct run");
+            }
+        }
+        debug!(
+            characters = position,
+            longest = ends.len(),
+            "scanned datastream"
+        );
+        Ok(Markers { ends })
+    }
+
+    /// Number of characters read until the first run of `window_size` distinct ones.
+    pub fn marker(&self, window_size: usize) -> Option<usize> {
+        self.ends.get(window_size.checked_sub(1)?).copied()
+    }
+}
+
+/// Solution of day 6, locating markers in the datastream.
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const EXAMPLE: Example = example!();
+
+    type Input = Markers;
+    type Config = Config;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::read(input.as_bytes())
+    }
+
+    fn read<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Markers::scan(chars(reader))
+    }
+
+    fn part1(markers: &Self::Input, config: &Self::Config) -> Result<Self::Part1> {
+        markers
+            .marker(config.packet_window)
+            .ok_or_else(|| Error::domain("Input doesn't have a signal marker"))
+    }
+
+    fn part2(markers: &Self::Input, config: &Self::Config) -> Result<Self::Part2> {
+        markers
+            .marker(config.message_window)
+            .ok_or_else(|| Error::domain("Input doesn't have a message marker"))
+    }
+}
+
+impl Generator for Day06 {
+    /// Writes `size` characters drawn from only ten letters, so a message marker can only
+    /// appear in the fourteen distinct letters appended at the end.
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let mut stream: Vec<char> = (0..size)
+            .map(|_| char::from(b'a' + rng.range(0..10) as u8))
+            .collect();
+        stream.extend('k'..='x');
+        // checks every window on its own, independent of the single pass of the solver
+        let marker = |window_size: usize| {
+            (window_size..=stream.len())
+                .find(|&end| {
+                    let window = &stream[end - window_size..end];
+                    window
+                        .iter()
+                        .enumerate()
+                        .all(|(index, c)| !window[index + 1..].contains(c))
+                })
+                .map(|end| end.to_string())
+        };
+        let (part1, part2) = (marker(4), marker(14));
+        let mut input: String = stream.into_iter().collect();
+        input.push('\n');
+        Generated {
+            input,
+            part1,
+            part2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{check_example, Solution};
+
+    use crate::Day06;
+
+    #[test]
+    fn solves_example() {
+        check_example::<Day06>().unwrap();
+    }
+
+    fn calculate_marker(input: &str, window_size: usize) -> Option<usize> {
+        Day06::parse(input).unwrap().marker(window_size)
+    }
+
+    #[test]
+    fn find_marker_sample1() {
+        let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        let result = calculate_marker(input, 4);
+        assert_eq!(result, Some(7));
+    }
+
+    #[test]
+    fn find_marker_sample2() {
+        let input = "bvwbjplbgvbhsrlpgdmjqwftvncz";
+        let result = calculate_marker(input, 4);
+        assert_eq!(result, Some(5));
+    }
+
+    #[test]
+    fn find_marker_sample3() {
+        let input = "nppdvjthqldpwncqszvftbrmjlhg";
+        let result = calculate_marker(input, 4);
+        assert_eq!(result, Some(6));
+    }
+
+    #[test]
+    fn find_marker_sample4() {
+        let input = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
+        let result = calculate_marker(input, 4);
+        assert_eq!(result, Some(10));
+    }
+
+    #[test]
+    fn find_marker_sample5() {
+        let input = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
+        let result = calculate_marker(input, 4);
+        assert_eq!(result, Some(11));
+    }
+
+    #[test]
+    fn find_message_sample1() {
+        let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        let result = calculate_marker(input, 14);
+        assert_eq!(result, Some(19));
+    }
+
+    #[test]
+    fn find_message_sample2() {
+        let input = "bvwbjplbgvbhsrlpgdmjqwftvncz";
+        let result = calculate_marker(input, 14);
+        assert_eq!(result, Some(23));
+    }
+
+    #[test]
+    fn find_message_sample3() {
+        let input = "nppdvjthqldpwncqszvftbrmjlhg";
+        let result = calculate_marker(input, 14);
+        assert_eq!(result, Some(23));
+    }
+
+    #[test]
+    fn find_message_sample4() {
+        let input = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
+        let result = calculate_marker(input, 14);
+        assert_eq!(result, Some(29));
+    }
+
+    #[test]
+    fn find_message_sample5() {
+        let input = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
+        let result = calculate_marker(input, 14);
+        assert_eq!(result, Some(26));
+    }
+
+    #[test]
+    fn handles_crlf_input() {
+        let markers = Day06::parse("\u{feff}mjqjpqmgbljsphdztnvjfqwrcgsmlb\r\n\r\n").unwrap();
+        assert_eq!(markers.marker(4), Some(7));
+        assert_eq!(markers.marker(14), Some(19));
+    }
+}
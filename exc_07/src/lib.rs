@@ -0,0 +1,587 @@
+//! Day 7: No Space Left On Device.
+//!
+//! The input is a terminal transcript of `cd` and `ls` commands, which [`Parser`] turns
+//! into a tree of [`DirectoryMetadata`] rooted at `/`.
+
+use std::{collections::HashSet, fmt::Display};
+
+use aoc_common::{
+    example,
+    nom::{
+        branch::alt,
+        bytes::complete::take_till1,
+        character::complete::{char, space1},
+        error::context,
+        sequence::{pair, preceded, separated_pair},
+        Parser as _,
+    },
+    normalize,
+    parse::{keyword, parse_all, unsigned, IResult},
+    serde_json::json,
+    tracing::{debug, trace},
+    Answer, Error, Example, Generated, Generator, Map, Params, ParseError, Result, Rng, Solution,
+    Value,
+};
+
+/// A file listed by `ls`.
+#[derive(Debug)]
+pub struct FileMetadata {
+    name: String,
+    size: usize,
+}
+
+impl FileMetadata {
+    pub fn new(name: &str, size: usize) -> Self {
+        FileMetadata {
+            name: name.to_owned(),
+            size,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+/// A directory together with everything the transcript listed in it.
+#[derive(Debug)]
+pub struct DirectoryMetadata {
+    name: String,
+    items: Vec<Node>,
+}
+
+impl DirectoryMetadata {
+    pub fn new(name: &str) -> Self {
+        DirectoryMetadata {
+            name: name.to_owned(),
+            items: Vec::new(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    pub fn items(&self) -> &[Node] {
+        self.items.as_ref()
+    }
+
+    /// All directories below this one, at any depth.
+    pub fn directories(&self) -> Vec<&DirectoryMetadata> {
+        self.items
+            .iter()
+            .filter_map(|item| match item {
+                Node::Directory(d) => {
+                    let result = [vec![d], d.directories()].concat();
+                    Some(result)
+                }
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Direct subdirectory called `name`.
+    pub fn find_subdirectory(&self, name: &str) -> Option<&DirectoryMetadata> {
+        if let Some(Node::Directory(directory)) = self.items.iter().find(|item| match item {
+            Node::Directory(d) => d.name() == name,
+            _ => false,
+        }) {
+            return Some(directory);
+        }
+
+        None
+    }
+
+    /// Total size of all files below this directory, at any depth.
+    pub fn size(&self) -> usize {
+        self.items
+            .iter()
+            .map(|node| match node {
+                Node::File(f) => f.size(),
+                Node::Directory(d) => d.size(),
+            })
+            .sum()
+    }
+
+    /// Fills this directory with the entries `parser` yields until it leaves the directory.
+    ///
+    /// Returns whether the directory was left with `cd ..` rather than by the end of the
+    /// transcript.
+    pub fn parse(&mut self, parser: &mut Parser) -> Result<bool, ParseError> {
+        loop {
+            match parser.next().transpose()? {
+                Some(ParseResult::Unknown) => continue,
+                Some(ParseResult::File(f)) => self.items.push(Node::File(f)),
+                Some(ParseResult::EnterDirectory(name)) => {
+                    trace!(name, parent = self.name(), "entering directory");
+                    let mut subdir = DirectoryMetadata::new(name);
+                    subdir.parse(parser)?;
+                    self.items.push(Node::Directory(subdir));
+                }
+                Some(ParseResult::LeaveDirectory) => return Ok(true),
+                None => return Ok(false),
+            }
+        }
+    }
+}
+
+/// An entry of a directory.
+#[derive(Debug)]
+pub enum Node {
+    File(FileMetadata),
+    Directory(DirectoryMetadata),
+}
+
+/// Meaning of a single transcript line.
+pub enum ParseResult<'a> {
+    /// A line that does not change the tree, like `$ ls` or a `dir` listing.
+    Unknown,
+    /// `$ cd name`
+    EnterDirectory(&'a str),
+    /// `$ cd ..`
+    LeaveDirectory,
+    /// A file listing with its size.
+    File(FileMetadata),
+}
+
+/// Iterates over the lines of a terminal transcript, each paired with its line number.
+pub struct Parser<'a> {
+    items: Vec<(usize, &'a str)>,
+    current_index: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(items: Vec<(usize, &'a str)>) -> Self {
+        Parser {
+            items,
+            current_index: 0,
+        }
+    }
+
+    /// Number of the line read last, if any.
+    pub fn line(&self) -> Option<usize> {
+        let index = self.current_index.checked_sub(1)?;
+        self.items.get(index).map(|&(line_no, _)| line_no)
+    }
+}
+
+impl<'a> Iterator for Parser<'a> {
+    type Item = Result<ParseResult<'a>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let &(line_no, item) = self.items.get(self.current_index)?;
+        self.current_index += 1;
+        Some(parse_all(item, entry).map_err(|err| err.at_line(line_no)))
+    }
+}
+
+/// A line of the transcript, like `$ cd a`, `$ ls`, `dir a` or `14848514 b.txt`.
+fn entry(input: &str) -> IResult<'_, ParseResult<'_>> {
+    let name = || take_till1(char::is_whitespace);
+    let command = alt((
+        preceded(keyword("cd"), name()).map(|name| match name {
+            ".." => ParseResult::LeaveDirectory,
+            name => ParseResult::EnterDirectory(name),
+        }),
+        keyword("ls").map(|_| ParseResult::Unknown),
+    ));
+    context(
+        "a command or listing",
+        alt((
+            preceded(pair(char('$'), space1), context("cd or ls", command)),
+            preceded(keyword("dir"), name()).map(|_| ParseResult::Unknown),
+            separated_pair(unsigned, space1, name())
+                .map(|(size, name)| ParseResult::File(FileMetadata::new(name, size))),
+        )),
+    )(input)
+}
+
+/// Parameters of the puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// Directories of at most this size are summed up in part 1.
+    pub threshold: usize,
+    /// Total space of the device.
+    pub disk_size: usize,
+    /// Free space the update needs.
+    pub required_space: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            threshold: 100000,
+            disk_size: 70000000,
+            required_space: 30000000,
+        }
+    }
+}
+
+impl aoc_common::Config for Config {
+    const KEYS: &'static [&'static str] = &["threshold", "disk_size", "required_space"];
+
+    fn from_params(params: &Params) -> Result<Self> {
+        let default = Config::default();
+        Ok(Config {
+            threshold: params.get("threshold", default.threshold)?,
+            disk_size: params.get("disk_size", default.disk_size)?,
+            required_space: params.get("required_space", default.required_space)?,
+        })
+    }
+}
+
+/// Smallest directory whose removal frees up enough space for the update.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cleanup {
+    directory: String,
+    size: usize,
+}
+
+impl Cleanup {
+    pub fn directory(&self) -> &str {
+        self.directory.as_ref()
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl Display for Cleanup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.size)
+    }
+}
+
+impl Answer for Cleanup {
+    fn details(&self) -> Map<String, Value> {
+        let mut details = Map::new();
+        details.insert("directory".to_owned(), json!(self.directory));
+        details.insert("size".to_owned(), json!(self.size));
+        details
+    }
+}
+
+/// Solution of day 7, freeing up space on the device.
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const EXAMPLE: Example = example!();
+
+    type Input = DirectoryMetadata;
+    type Config = Config;
+    type Part1 = usize;
+    type Part2 = Cleanup;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut root_dir = DirectoryMetadata::new("/");
+        let input = normalize(input);
+        let lines: Vec<_> = input
+            .split('\n')
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(index, line)| (index + 1, line.trim()))
+            .collect();
+        let mut parser = Parser::new(lines);
+        if !matches!(
+            parser.next().transpose()?,
+            Some(ParseResult::EnterDirectory("/"))
+        ) {
+            return Err(
+                ParseError::new("Expected the transcript to start with $ cd /.")
+                    .at_line(parser.line().unwrap_or(1))
+                    .into(),
+            );
+        }
+        if root_dir.parse(&mut parser)? {
+            return Err(Error::domain(format!(
+                "Line {} leaves the root directory with cd ..",
+                parser.line().unwrap_or_default()
+            )));
+        }
+        debug!(size = root_dir.size(), "parsed directory tree");
+        Ok(root_dir)
+    }
+
+    fn part1(root_dir: &Self::Input, config: &Self::Config) -> Result<Self::Part1> {
+        Ok(root_dir
+            .directories()
+            .into_iter()
+            .filter(|dir| dir.size() <= config.threshold)
+            .map(|dir| dir.size())
+            .sum())
+    }
+
+    fn part2(root_dir: &Self::Input, config: &Self::Config) -> Result<Self::Part2> {
+        let free_space = config
+            .disk_size
+            .checked_sub(root_dir.size())
+            .ok_or_else(|| Error::domain("Files take up more space than the device has"))?;
+        let required_space = config
+            .required_space
+            .checked_sub(free_space)
+            .ok_or_else(|| Error::domain("Device already has enough space for the update"))?;
+
+        let mut dirs = root_dir.directories();
+        dirs.sort_by_key(|a| a.size());
+        let remove_dir = dirs
+            .into_iter()
+            .find(|dir| dir.size() >= required_space)
+            .ok_or_else(|| {
+                Error::domain("No matching directory found to clear up enough space on device")
+            })?;
+        Ok(Cleanup {
+            directory: remove_dir.name().to_owned(),
+            size: remove_dir.size(),
+        })
+    }
+}
+
+/// A directory of a generated tree, its subdirectories are referred to by index.
+#[derive(Default)]
+struct GeneratedDirectory {
+    name: String,
+    files: Vec<(String, usize)>,
+    children: Vec<usize>,
+    /// Names of all entries, which have to be unique.
+    taken: HashSet<String>,
+}
+
+impl Generator for Day07 {
+    /// Writes the transcript of exploring a random tree of `size` files and directories.
+    ///
+    /// File sizes add up to between 40 and 70 million, so the update needs a cleanup as
+    /// in the original puzzle.
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let mut dirs = vec![GeneratedDirectory {
+            name: "/".to_owned(),
+            ..Default::default()
+        }];
+        for _ in 0..size {
+            let parent = rng.range(0..dirs.len());
+            if rng.chance(1, 3) {
+                let name = entry_name(rng, &mut dirs[parent].taken, false);
+                let child = dirs.len();
+                dirs[parent].children.push(child);
+                dirs.push(GeneratedDirectory {
+                    name,
+                    ..Default::default()
+                });
+            } else {
+                let name = entry_name(rng, &mut dirs[parent].taken, true);
+                // weight of the file, scaled to its size below
+                let weight = rng.range(1..1000);
+                dirs[parent].files.push((name, weight));
+            }
+        }
+
+        let total_weight = dirs
+            .iter()
+            .flat_map(|dir| &dir.files)
+            .map(|f| f.1)
+            .sum::<usize>()
+            .max(1);
+        let target = rng.range(40_000_001..70_000_000);
+        for (_, size) in dirs.iter_mut().flat_map(|dir| &mut dir.files) {
+            *size = (*size * target / total_weight).max(1);
+        }
+        // subdirectories always come after their parent
+        let mut sizes = vec![0; dirs.len()];
+        for index in (0..dirs.len()).rev() {
+            sizes[index] = dirs[index].files.iter().map(|f| f.1).sum::<usize>()
+                + dirs[index]
+                    .children
+                    .iter()
+                    .map(|&c| sizes[c])
+                    .sum::<usize>();
+        }
+
+        let mut input = "$ cd /\n".to_owned();
+        write_transcript(rng, &dirs, 0, &mut input);
+
+        let config = Config::default();
+        let part1 = sizes
+            .iter()
+            .filter(|&&size| size <= config.threshold)
+            .sum::<usize>();
+        let part2 = config
+            .disk_size
+            .checked_sub(sizes[0])
+            .and_then(|free| config.required_space.checked_sub(free))
+            // removing `/` itself is not an option
+            .and_then(|required| sizes[1..].iter().filter(|&&size| size >= required).min());
+        Generated {
+            input,
+            part1: Some(part1.to_string()),
+            part2: part2.map(ToString::to_string),
+        }
+    }
+}
+
+/// Random name not in `taken` yet, files may get an extension.
+fn entry_name(rng: &mut Rng, taken: &mut HashSet<String>, file: bool) -> String {
+    let word = |rng: &mut Rng, length: usize| -> String {
+        (0..length)
+            .map(|_| char::from(b'a' + rng.range(0..26) as u8))
+            .collect()
+    };
+    loop {
+        let length = rng.range(2..9);
+        let mut name = word(rng, length);
+        if file && rng.chance(1, 2) {
+            name = format!("{name}.{}", word(rng, 3));
+        }
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+/// Lists the directory at `index` and then enters each of its subdirectories.
+fn write_transcript(rng: &mut Rng, dirs: &[GeneratedDirectory], index: usize, out: &mut String) {
+    let dir = &dirs[index];
+    let mut listing: Vec<_> = dir
+        .children
+        .iter()
+        .map(|&child| format!("dir {}", dirs[child].name))
+        .chain(
+            dir.files
+                .iter()
+                .map(|(name, size)| format!("{size} {name}")),
+        )
+        .collect();
+    rng.shuffle(&mut listing);
+    out.push_str("$ ls\n");
+    for entry in listing {
+        out.push_str(&entry);
+        out.push('\n');
+    }
+    for &child in &dir.children {
+        out.push_str(&format!("$ cd {}\n", dirs[child].name));
+        write_transcript(rng, dirs, child, out);
+        out.push_str("$ cd ..\n");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{check_example, Error, Solution};
+
+    use crate::{Config, Day07};
+
+    #[test]
+    fn solves_example() {
+        check_example::<Day07>().unwrap();
+    }
+
+    #[test]
+    fn processes_sample_part1() {
+        let input = Day07::EXAMPLE.input;
+
+        let root_dir = Day07::parse(input).unwrap();
+        assert_eq!(root_dir.name(), "/");
+        assert_eq!(root_dir.size(), 48381165);
+        assert_eq!(root_dir.find_subdirectory("a").unwrap().size(), 94853);
+        assert_eq!(root_dir.find_subdirectory("d").unwrap().size(), 24933642);
+
+        let dirs = root_dir.directories();
+        assert_eq!(dirs.len(), 3);
+        let dir_size: usize = dirs
+            .into_iter()
+            .filter(|dir| dir.size() < 100000)
+            .map(|dir| dir.size())
+            .sum();
+        assert_eq!(dir_size, 95437);
+        assert_eq!(Day07::part1(&root_dir, &Config::default()).unwrap(), 95437);
+    }
+
+    #[test]
+    fn processes_sample_part2() {
+        let input = Day07::EXAMPLE.input;
+
+        let root_dir = Day07::parse(input).unwrap();
+
+        let free_space = 70000000 - root_dir.size();
+        let required_space = 30000000 - free_space;
+
+        let mut dirs = root_dir.directories();
+        assert_eq!(dirs.len(), 3);
+        dirs.sort_by_key(|a| a.size());
+        let remove_dirs: Vec<_> = dirs
+            .into_iter()
+            .filter(|dir| dir.size() >= required_space)
+            .take(1)
+            .collect();
+        assert_eq!(remove_dirs.len(), 1);
+        assert_eq!(remove_dirs.first().unwrap().name(), "d");
+        assert_eq!(remove_dirs.first().unwrap().size(), 24933642);
+        let cleanup = Day07::part2(&root_dir, &Config::default()).unwrap();
+        assert_eq!(cleanup.directory(), "d");
+        assert_eq!(cleanup.size(), 24933642);
+    }
+
+    #[test]
+    fn sums_directories_at_the_threshold() {
+        let input = "$ cd /\n$ ls\ndir a\ndir b\n$ cd a\n$ ls\n100000 f\n$ cd ..\n\
+                     $ cd b\n$ ls\n100001 g\n";
+        let root_dir = Day07::parse(input).unwrap();
+        assert_eq!(Day07::part1(&root_dir, &Config::default()).unwrap(), 100000);
+    }
+
+    #[test]
+    fn reports_invalid_lines() {
+        let err = Day07::parse("$ cd /\n$ ls\ndir a\n123 b.txt\nfoo\n").unwrap_err();
+        assert_eq!(err.to_string(), "input:5:1: Expected a command or listing.");
+        let err = Day07::parse("$ cd /\n$ rm a\n").unwrap_err();
+        assert_eq!(err.to_string(), "input:2:3: Expected cd or ls.");
+    }
+
+    #[test]
+    fn reports_invalid_transcripts() {
+        let err = Day07::parse("$ ls\n123 b.txt\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "input:1: Expected the transcript to start with $ cd /."
+        );
+        let err = Day07::parse("").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "input:1: Expected the transcript to start with $ cd /."
+        );
+
+        let err = Day07::parse("$ cd /\n$ cd ..\n$ ls\n").unwrap_err();
+        assert!(matches!(err, Error::Domain(_)));
+        assert_eq!(
+            err.to_string(),
+            "Line 2 leaves the root directory with cd .."
+        );
+        assert!(Day07::parse("$ cd /\n$ cd a\n$ cd ..\n$ cd ..\n").is_err());
+    }
+
+    #[test]
+    fn reports_missing_directory() {
+        let root_dir = Day07::parse("$ cd /\n$ ls\n69000000 a.bin\n").unwrap();
+        let err = Day07::part2(&root_dir, &Config::default()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "No matching directory found to clear up enough space on device"
+        );
+        assert_eq!(err.exit_code(), 5);
+    }
+
+    #[test]
+    fn handles_crlf_input() {
+        let input = "\u{feff}$ cd /\r\n$ ls\r\ndir a\r\n14848514 b.txt\r\n\
+                     $ cd a\r\n$ ls\r\n29116 f\r\n$ cd ..\r\n\r\n";
+        let root_dir = Day07::parse(input).unwrap();
+        assert_eq!(root_dir.size(), 14848514 + 29116);
+        assert_eq!(root_dir.find_subdirectory("a").unwrap().size(), 29116);
+        assert_eq!(Day07::part1(&root_dir, &Config::default()).unwrap(), 29116);
+    }
+}
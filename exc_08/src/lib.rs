@@ -0,0 +1,279 @@
+//! Day 8: Treetop Tree House.
+//!
+//! The input is a grid of tree heights from 0 to 9, one row per line.
+
+use std::{fmt::Display, io::BufRead};
+
+use aoc_common::{
+    example, lines,
+    nom::{
+        character::complete::anychar,
+        combinator::{cut, eof, map_opt, not},
+        error::context,
+        multi::many1,
+        sequence::preceded,
+    },
+    parse::IResult,
+    serde_json::json,
+    tracing::{debug, trace},
+    Answer, Direction, Error, Example, Generated, Generator, Grid, Map, ParseError, Point, Result,
+    Rng, Solution, Value,
+};
+
+const RADIX: u32 = 10;
+
+/// A row of tree heights like `30373`.
+fn row(input: &str) -> IResult<'_, Vec<u32>> {
+    let height = map_opt(anychar, |c| c.to_digit(RADIX));
+    many1(preceded(not(eof), cut(context("a tree height", height))))(input)
+}
+
+/// Number of trees visible from outside the grid, split by trees on and within the edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Visibility {
+    pub outer: usize,
+    pub inner: usize,
+}
+
+impl Display for Visibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.outer + self.inner)
+    }
+}
+
+impl Answer for Visibility {
+    fn details(&self) -> Map<String, Value> {
+        let mut details = Map::new();
+        details.insert("outer".to_owned(), json!(self.outer));
+        details.insert("inner".to_owned(), json!(self.inner));
+        details
+    }
+}
+
+/// Whether the tree at `point` can be seen from outside the grid in any direction.
+fn is_visible(grid: &Grid<u32>, point: Point) -> bool {
+    let height = grid[point];
+    Direction::ORTHOGONAL
+        .into_iter()
+        .any(|direction| grid.ray(point, direction).all(|(_, &tree)| tree < height))
+}
+
+/// Number of trees seen from `point` in `direction`, up to the first one at least as tall.
+fn viewing_distance(grid: &Grid<u32>, point: Point, direction: Direction) -> usize {
+    let height = grid[point];
+    let mut distance = 0;
+    for (_, &tree) in grid.ray(point, direction) {
+        distance += 1;
+        if tree >= height {
+            break;
+        }
+    }
+    distance
+}
+
+/// Product of the viewing distances in all four directions.
+fn scenic_score(grid: &Grid<u32>, point: Point) -> usize {
+    Direction::ORTHOGONAL
+        .into_iter()
+        .map(|direction| viewing_distance(grid, point, direction))
+        .product()
+}
+
+/// Solution of day 8, counting visible trees and finding the best view.
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const EXAMPLE: Example = example!();
+
+    type Input = Grid<u32>;
+    type Config = ();
+    type Part1 = Visibility;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::read(input.as_bytes())
+    }
+
+    fn read<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let mut grid: Option<Grid<u32>> = None;
+        for line in lines(reader) {
+            let line = line?;
+            if line.text.trim().is_empty() {
+                continue;
+            }
+            let row = line.parse(row)?;
+            let grid = grid.get_or_insert_with(|| Grid::new(row.len()));
+            if row.len() != grid.cols() {
+                return Err(ParseError::new(format!(
+                    "Expected {} trees in a row, but found {}.",
+                    grid.cols(),
+                    row.len()
+                ))
+                .at_line(line.number)
+                .into());
+            }
+            grid.push_row(row);
+        }
+        let grid = grid.ok_or_else(|| ParseError::new("Input doesn't contain any trees."))?;
+        debug!(rows = grid.rows(), cols = grid.cols(), "parsed forest");
+        Ok(grid)
+    }
+
+    fn part1(grid: &Self::Input, _config: &Self::Config) -> Result<Self::Part1> {
+        if grid.rows() < 2 || grid.cols() < 2 {
+            return Err(Error::domain(
+                "Grid needs at least two rows and columns of trees",
+            ));
+        }
+
+        // trees on the edge are always visible
+        let (mut outer, mut inner) = (0, 0);
+        for point in grid.points() {
+            if grid.is_on_edge(point) {
+                outer += 1;
+            } else if is_visible(grid, point) {
+                inner += 1;
+            }
+        }
+
+        Ok(Visibility { outer, inner })
+    }
+
+    fn part2(grid: &Self::Input, _config: &Self::Config) -> Result<Self::Part2> {
+        // trees on the edge always have a score of zero
+        let mut best = 0;
+        for point in grid.points() {
+            let score = scenic_score(grid, point);
+            if score > best {
+                trace!(%point, score, "found better scenic score");
+                best = score;
+            }
+        }
+        Ok(best)
+    }
+}
+
+impl Generator for Day08 {
+    /// Plants a square forest of `size` by `size` trees with random heights.
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let heights: Vec<Vec<u8>> = (0..size)
+            .map(|_| (0..size).map(|_| rng.range(0..10) as u8).collect())
+            .collect();
+        let mut input = String::new();
+        for row in &heights {
+            input.extend(row.iter().map(|height| char::from(b'0' + height)));
+            input.push('\n');
+        }
+
+        // walks from every tree in all four directions
+        let (mut visible, mut best_score) = (0, 0);
+        for row in 0..size {
+            for col in 0..size {
+                let height = heights[row][col];
+                let lines: [Vec<u8>; 4] = [
+                    (0..row).rev().map(|r| heights[r][col]).collect(),
+                    (row + 1..size).map(|r| heights[r][col]).collect(),
+                    (0..col).rev().map(|c| heights[row][c]).collect(),
+                    (col + 1..size).map(|c| heights[row][c]).collect(),
+                ];
+                if lines
+                    .iter()
+                    .any(|line| line.iter().all(|&tree| tree < height))
+                {
+                    visible += 1;
+                }
+                let score: usize = lines
+                    .iter()
+                    .map(|line| match line.iter().position(|&tree| tree >= height) {
+                        Some(index) => index + 1,
+                        None => line.len(),
+                    })
+                    .product();
+                best_score = best_score.max(score);
+            }
+        }
+        let solvable = size >= 2;
+        Generated {
+            input,
+            part1: solvable.then(|| visible.to_string()),
+            part2: solvable.then(|| best_score.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{check_example, Direction, Point, Solution};
+
+    use crate::{is_visible, scenic_score, viewing_distance, Day08, Visibility};
+
+    #[test]
+    fn solves_example() {
+        check_example::<Day08>().unwrap();
+    }
+
+    #[test]
+    fn processes_sample_part1() {
+        let input = Day08::EXAMPLE.input;
+        let grid = Day08::parse(input).unwrap();
+        assert_eq!(grid.get(Point::new(0, 0)), Some(&3));
+        assert_eq!(grid.get(Point::new(4, 4)), Some(&0));
+        let outer = grid
+            .points()
+            .filter(|&point| grid.is_on_edge(point))
+            .count();
+        assert_eq!(outer, 16);
+
+        let inner = grid
+            .points()
+            .filter(|&point| !grid.is_on_edge(point) && is_visible(&grid, point))
+            .count();
+        assert!(is_visible(&grid, Point::new(1, 1)));
+        assert!(!is_visible(&grid, Point::new(2, 2)));
+        assert_eq!(inner, 5);
+        let visibility = Day08::part1(&grid, &()).unwrap();
+        assert_eq!(visibility, Visibility { outer, inner });
+        assert_eq!(visibility.to_string(), "21");
+    }
+
+    #[test]
+    fn processes_sample_part2() {
+        let input = Day08::EXAMPLE.input;
+        let grid = Day08::parse(input).unwrap();
+        let tree = Point::new(2, 3);
+        assert_eq!(grid[tree], 5);
+        let distances: Vec<_> = Direction::ORTHOGONAL
+            .into_iter()
+            .map(|direction| viewing_distance(&grid, tree, direction))
+            .collect();
+        assert_eq!(distances, [2, 2, 1, 2]);
+
+        let view_score: Vec<_> = grid
+            .points()
+            .map(|point| scenic_score(&grid, point))
+            .collect();
+        assert_eq!(view_score.into_iter().max(), Some(8));
+        assert_eq!(Day08::part2(&grid, &()).unwrap(), 8);
+    }
+
+    #[test]
+    fn reports_invalid_grid() {
+        let err = Day08::parse("30373\n25a12\n").unwrap_err();
+        assert_eq!(err.to_string(), "input:2:3: Expected a tree height.");
+
+        let err = Day08::parse("30373\n2551\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "input:2: Expected 5 trees in a row, but found 4."
+        );
+    }
+
+    #[test]
+    fn handles_crlf_input() {
+        let input = "\u{feff}30373\r\n25512\r\n65332\r\n33549\r\n35390\r\n\r\n";
+        let grid = Day08::parse(input).unwrap();
+        assert_eq!(Day08::part1(&grid, &()).unwrap().to_string(), "21");
+        assert_eq!(Day08::part2(&grid, &()).unwrap(), 8);
+    }
+}
@@ -0,0 +1,223 @@
+//! Day 9: Rope Bridge.
+//!
+//! The input lists the motions of the rope's head, the answer is the number of positions
+//! the tail visits while following it.
+
+use std::{collections::HashSet, io::BufRead};
+
+use aoc_common::{
+    example, lines,
+    nom::{
+        branch::alt,
+        character::complete::{char, space1},
+        combinator::value,
+        error::context,
+        sequence::separated_pair,
+        Parser,
+    },
+    parse::{unsigned, IResult},
+    tracing::trace,
+    Direction, Example, Generated, Generator, Point, Result, Rng, Solution, Unsolved,
+};
+
+/// A motion of the head by a number of steps, written as `R 4` in the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Motion {
+    pub direction: Direction,
+    pub steps: u32,
+}
+
+/// A motion like `R 4`, the direction being one of `R`, `L`, `U` and `D`.
+fn motion(input: &str) -> IResult<'_, Motion> {
+    let direction = alt((
+        value(Direction::East, char('R')),
+        value(Direction::West, char('L')),
+        value(Direction::North, char('U')),
+        value(Direction::South, char('D')),
+    ));
+    separated_pair(
+        context("a direction R, L, U or D", direction),
+        space1,
+        context("a step count", unsigned),
+    )
+    .map(|(direction, steps)| Motion { direction, steps })
+    .parse(input)
+}
+
+/// A rope with a head and a tail, tracking every position the tail has visited.
+#[derive(Debug)]
+pub struct Board {
+    head: Point,
+    tail: Point,
+    tail_positions: HashSet<Point>,
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Board {
+    pub fn new() -> Self {
+        Board {
+            head: Point::ORIGIN,
+            tail: Point::ORIGIN,
+            tail_positions: HashSet::from([Point::ORIGIN]),
+        }
+    }
+
+    pub fn head(&self) -> Point {
+        self.head
+    }
+
+    pub fn tail(&self) -> Point {
+        self.tail
+    }
+
+    pub fn tail_positions(&self) -> &HashSet<Point> {
+        &self.tail_positions
+    }
+
+    /// Moves the head step by step, the tail follows once it no longer touches the head.
+    pub fn update(&mut self, motion: Motion) {
+        for _ in 0..motion.steps {
+            self.head += motion.direction.vector();
+            if self.head.chebyshev(self.tail) > 1 {
+                self.tail += (self.head - self.tail).signum();
+                self.tail_positions.insert(self.tail);
+            }
+        }
+    }
+}
+
+/// Solution of day 9, simulating the rope bridge.
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const EXAMPLE: Example = example!();
+
+    type Input = Vec<Motion>;
+    type Config = ();
+    type Part1 = usize;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::read(input.as_bytes())
+    }
+
+    fn read<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let mut motions = Vec::new();
+        for line in lines(reader) {
+            let line = line?;
+            if !line.text.trim().is_empty() {
+                motions.push(line.parse(motion)?);
+            }
+        }
+        Ok(motions)
+    }
+
+    fn part1(directions: &Self::Input, _config: &Self::Config) -> Result<Self::Part1> {
+        let mut board = Board::new();
+
+        for direction in directions {
+            board.update(*direction);
+            trace!(
+                ?direction,
+                visited = board.tail_positions().len(),
+                "moved head"
+            );
+        }
+
+        Ok(board.tail_positions().len())
+    }
+
+    fn part2(_motions: &Self::Input, _config: &Self::Config) -> Result<Self::Part2> {
+        Ok(Unsolved)
+    }
+}
+
+impl Generator for Day09 {
+    /// Writes `size` motions of up to ten steps each.
+    ///
+    /// The answers follow the rules of the puzzle: a knot more than one step away from
+    /// the one ahead moves one step towards it, diagonally if needed. Both the rope of
+    /// part 1 with two knots and the one of part 2 with ten knots are simulated.
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let mut input = String::new();
+        let mut knots = [(0_i32, 0_i32); 10];
+        let mut visited = [HashSet::from([(0, 0)]), HashSet::from([(0, 0)])];
+        for _ in 0..size {
+            let (name, (dx, dy)) =
+                *rng.pick(&[('R', (1, 0)), ('L', (-1, 0)), ('U', (0, 1)), ('D', (0, -1))]);
+            let steps = rng.range(1..11);
+            input.push_str(&format!("{name} {steps}\n"));
+            for _ in 0..steps {
+                knots[0] = (knots[0].0 + dx, knots[0].1 + dy);
+                for index in 1..knots.len() {
+                    let (ahead, knot) = (knots[index - 1], &mut knots[index]);
+                    if (ahead.0 - knot.0).abs() > 1 || (ahead.1 - knot.1).abs() > 1 {
+                        knot.0 += (ahead.0 - knot.0).signum();
+                        knot.1 += (ahead.1 - knot.1).signum();
+                    }
+                }
+                visited[0].insert(knots[1]);
+                visited[1].insert(knots[9]);
+            }
+        }
+        Generated {
+            input,
+            part1: Some(visited[0].len().to_string()),
+            part2: Some(visited[1].len().to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{check_example, Point, Solution};
+
+    use crate::{Board, Day09};
+
+    #[test]
+    fn solves_example() {
+        check_example::<Day09>().unwrap();
+    }
+
+    #[test]
+    fn processes_sample1() {
+        let input = Day09::EXAMPLE.input;
+
+        let motions = Day09::parse(input).unwrap();
+
+        let mut board = Board::new();
+        for motion in motions.iter() {
+            board.update(*motion);
+        }
+
+        assert_eq!(board.head(), Point::new(2, -2));
+        assert_eq!(board.tail(), Point::new(1, -2));
+        assert_eq!(board.tail_positions().len(), 13);
+        assert_eq!(Day09::part1(&motions, &()).unwrap(), 13);
+    }
+
+    #[test]
+    fn reports_invalid_motion() {
+        let err = Day09::parse("R 4\nU 4\nX 3\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "input:3:1: Expected a direction R, L, U or D."
+        );
+
+        let err = Day09::parse("R 4\nU four\n").unwrap_err();
+        assert_eq!(err.to_string(), "input:2:3: Expected a step count.");
+    }
+
+    #[test]
+    fn handles_crlf_input() {
+        let input = "\u{feff}R 4\r\nU 4\r\nL 3\r\nD 1\r\nR 4\r\nD 1\r\nL 5\r\nR 2\r\n\r\n";
+        let motions = Day09::parse(input).unwrap();
+        assert_eq!(Day09::part1(&motions, &()).unwrap(), 13);
+    }
+}